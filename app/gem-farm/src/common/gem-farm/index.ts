@@ -80,6 +80,18 @@ export class GemFarm extends GemFarmClient {
     return result;
   }
 
+  async setMaxCatchupWallet(farm: PublicKey, maxCatchupPerCall: BN) {
+    const result = await this.setMaxCatchup(
+      farm,
+      this.wallet.publicKey,
+      maxCatchupPerCall
+    );
+
+    console.log('set max catch-up for the farm');
+
+    return result;
+  }
+
   async authorizeFunderWallet(farm: PublicKey, funder: PublicKey) {
     const result = await this.authorizeFunder(
       farm,
@@ -237,6 +249,25 @@ export class GemFarm extends GemFarmClient {
     return result;
   }
 
+  async treasuryTokenPayoutWallet(
+    farm: PublicKey,
+    mint: PublicKey,
+    receiver: PublicKey,
+    amount: string
+  ) {
+    const result = await this.payoutTokensFromTreasury(
+      farm,
+      this.wallet.publicKey,
+      mint,
+      receiver,
+      new BN(amount)
+    );
+
+    console.log('paid out tokens from treasury', amount);
+
+    return result;
+  }
+
   async initFarmerWallet(farm: PublicKey) {
     const result = await this.initFarmer(
       farm,
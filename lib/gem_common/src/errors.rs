@@ -97,16 +97,35 @@ pub enum ErrorCode {
     #[msg("wrong metadata account, gem mint doesn't match")]
     WrongMetadata,
 
-    Reserved48,
-    Reserved49,
-    Reserved50,
-    Reserved51,
-    Reserved52,
-    Reserved53,
-    Reserved54,
-    Reserved55,
-    Reserved56,
-    Reserved57,
+    #[msg("the reward hasn't ended yet")]
+    RewardNotEnded, //0x15C
+
+    #[msg("some farmers are still staked")]
+    FarmersStillStaked,
+
+    #[msg("lock can't be shortened or extended past the reward's end")]
+    InvalidLockEnd,
+
+    #[msg("reward escrow is frozen by the mint's freeze authority")]
+    EscrowFrozen,
+
+    #[msg("prices must be non-zero")]
+    InvalidPrice,
+
+    #[msg("tranche can't unlock before the min staking and cooldown periods are over")]
+    InvalidUnlockTs,
+
+    #[msg("tranche hasn't unlocked yet")]
+    TrancheLocked,
+
+    #[msg("all unstake tranches are already in use")]
+    UnstakeScheduleFull,
+
+    #[msg("reward accrued before credited totals were tracked, can't be sunset")]
+    CreditsNotTracked,
+
+    #[msg("the farmer's referrer has already been set")]
+    ReferrerAlreadySet,
     Reserved58,
     Reserved59,
 }
@@ -130,11 +130,14 @@ fn assert_valid_whitelist_proof<'info>(
     proof.contains_type(expected_whitelist_type)
 }
 
-fn assert_whitelisted(ctx: &Context<DepositGem>) -> ProgramResult {
-    let bank = &*ctx.accounts.bank;
-    let mint = &*ctx.accounts.gem_mint;
-    let remaining_accs = &mut ctx.remaining_accounts.iter();
-
+/// consumes whitelist proofs (and metadata, if needed) from the passed account iterator
+/// shared with batch deposits, which pass several groups of these in a single ix
+pub fn assert_whitelisted<'info>(
+    bank: &Account<'info, Bank>,
+    mint: &Pubkey,
+    remaining_accs: &mut std::slice::Iter<AccountInfo<'info>>,
+    program_id: &Pubkey,
+) -> ProgramResult {
     // whitelisted mint is always the 1st optional account
     // this is because it's applicable to both NFTs and standard fungible tokens
     let mint_whitelist_proof_info = next_account_info(remaining_accs)?;
@@ -144,8 +147,8 @@ fn assert_whitelisted(ctx: &Context<DepositGem>) -> ProgramResult {
         if let Ok(()) = assert_valid_whitelist_proof(
             mint_whitelist_proof_info,
             &bank.key(),
-            &mint.key(),
-            ctx.program_id,
+            mint,
+            program_id,
             WhitelistType::MINT,
         ) {
             // msg!("mint whitelisted: {}, going ahead", &mint.key());
@@ -160,7 +163,7 @@ fn assert_whitelisted(ctx: &Context<DepositGem>) -> ProgramResult {
         let creator_whitelist_proof_info = next_account_info(remaining_accs)?;
 
         // verify metadata is legit
-        let metadata = assert_valid_metadata(metadata_info, &mint.key())?;
+        let metadata = assert_valid_metadata(metadata_info, mint)?;
 
         // metaplex constraints this to max 5, so won't go crazy on compute
         // (empirical testing showed there's practically 0 diff between stopping at 0th and 5th creator)
@@ -175,7 +178,7 @@ fn assert_whitelisted(ctx: &Context<DepositGem>) -> ProgramResult {
                 creator_whitelist_proof_info,
                 &bank.key(),
                 &creator.address,
-                ctx.program_id,
+                program_id,
                 WhitelistType::CREATOR,
             );
 
@@ -207,7 +210,12 @@ pub fn handler(ctx: Context<DepositGem>, amount: u64) -> ProgramResult {
     let bank = &*ctx.accounts.bank;
 
     if bank.whitelisted_mints > 0 || bank.whitelisted_creators > 0 {
-        assert_whitelisted(&ctx)?;
+        assert_whitelisted(
+            bank,
+            &ctx.accounts.gem_mint.key(),
+            &mut ctx.remaining_accounts.iter(),
+            ctx.program_id,
+        )?;
     }
 
     // verify vault not suspended
@@ -0,0 +1,254 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_spl::token::{self, InitializeAccount, Token, TokenAccount, Transfer};
+use gem_common::{errors::ErrorCode, *};
+
+use crate::instructions::{assert_whitelisted, calc_rarity_points};
+use crate::state::*;
+
+/// spl token accounts are always this size
+const TOKEN_ACCOUNT_LEN: usize = 165;
+
+#[derive(Accounts)]
+#[instruction(bump_auth: u8)]
+pub struct DepositGemsBatch<'info> {
+    // bank
+    pub bank: Box<Account<'info, Bank>>,
+
+    // vault
+    // same as with single deposits, vault PDA verification skipped - vault has owner -> owner is signer
+    #[account(mut, has_one = bank, has_one = owner, has_one = authority)]
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(seeds = [vault.key().as_ref()], bump = bump_auth)]
+    pub authority: AccountInfo<'info>,
+
+    // misc
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    //
+    // remaining accounts are passed as one group per gem, in this order:
+    //   #[account(mut)]
+    //   pub gem_box: Box<Account<'info, TokenAccount>>,
+    //   #[account(mut)]
+    //   pub gem_deposit_receipt: Box<Account<'info, GemDepositReceipt>>,
+    //   #[account(mut)]
+    //   pub gem_source: Box<Account<'info, TokenAccount>>,
+    //   pub gem_mint: Box<Account<'info, Mint>>,
+    //   pub gem_rarity: AccountInfo<'info>,
+    // followed by (only if the bank has any whitelists):
+    // - mint_whitelist_proof
+    // - gem_metadata <- only if the bank has creator whitelists
+    // - creator_whitelist_proof <- only if the bank has creator whitelists
+    //
+    // (!) unlike single deposits, every group must be the same length,
+    // so unused proofs have to be padded out with any placeholder account
+}
+
+impl<'info> DepositGemsBatch<'info> {
+    fn transfer_ctx(
+        &self,
+        from: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: from.clone(),
+                to: to.clone(),
+                authority: self.owner.to_account_info(),
+            },
+        )
+    }
+
+    fn init_gem_box_ctx(
+        &self,
+        gem_box: &AccountInfo<'info>,
+        gem_mint: &AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, InitializeAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            InitializeAccount {
+                account: gem_box.clone(),
+                mint: gem_mint.clone(),
+                authority: self.authority.clone(),
+                rent: self.rent.to_account_info(),
+            },
+        )
+    }
+}
+
+fn group_len(bank: &Bank) -> usize {
+    let mut len = 5;
+    if bank.whitelisted_mints > 0 || bank.whitelisted_creators > 0 {
+        len += 1;
+    }
+    if bank.whitelisted_creators > 0 {
+        len += 2;
+    }
+    len
+}
+
+/// find the PDA program-side (to keep tx size down) and verify it matches the passed account
+fn assert_pda(
+    seeds: &[&[u8]],
+    pda_info: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<u8, ProgramError> {
+    let (pda, bump) = Pubkey::find_program_address(seeds, program_id);
+    if pda != pda_info.key() {
+        return Err(ErrorCode::InvalidParameter.into());
+    }
+    Ok(bump)
+}
+
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, DepositGemsBatch<'info>>,
+    amounts: Vec<u64>,
+) -> ProgramResult {
+    // verify vault not suspended
+    let bank = &ctx.accounts.bank;
+    let vault = &ctx.accounts.vault;
+
+    if vault.access_suspended(bank.flags)? {
+        return Err(ErrorCode::VaultAccessSuspended.into());
+    }
+
+    let group_len = group_len(bank);
+    let verify_whitelist = group_len > 5;
+
+    if amounts.is_empty() || ctx.remaining_accounts.len() != amounts.len().try_mul(group_len)? {
+        return Err(ErrorCode::InvalidParameter.into());
+    }
+
+    let bank_key = bank.key();
+    let vault_key = vault.key();
+
+    let mut new_gem_box_count: u64 = 0;
+    let mut total_gem_count: u64 = 0;
+    let mut total_rarity_points: u64 = 0;
+
+    for (amount, group) in amounts.iter().zip(ctx.remaining_accounts.chunks(group_len)) {
+        let accs = &mut group.iter();
+        let gem_box_info = next_account_info(accs)?;
+        let gdr_info = next_account_info(accs)?;
+        let gem_source_info = next_account_info(accs)?;
+        let gem_mint_info = next_account_info(accs)?;
+        let gem_rarity_info = next_account_info(accs)?;
+
+        // if even a single whitelist exists, verify the token against it
+        if verify_whitelist {
+            assert_whitelisted(bank, &gem_mint_info.key(), accs, ctx.program_id)?;
+        }
+
+        // verify all 3 PDAs belong to this vault / bank and mint
+        let bump_gem_box = assert_pda(
+            &[
+                b"gem_box".as_ref(),
+                vault_key.as_ref(),
+                gem_mint_info.key().as_ref(),
+            ],
+            gem_box_info,
+            ctx.program_id,
+        )?;
+        let bump_gdr = assert_pda(
+            &[
+                b"gem_deposit_receipt".as_ref(),
+                vault_key.as_ref(),
+                gem_mint_info.key().as_ref(),
+            ],
+            gdr_info,
+            ctx.program_id,
+        )?;
+        assert_pda(
+            &[
+                b"gem_rarity".as_ref(),
+                bank_key.as_ref(),
+                gem_mint_info.key().as_ref(),
+            ],
+            gem_rarity_info,
+            ctx.program_id,
+        )?;
+
+        // create the gem box if this is the first time this mint is deposited
+        if gem_box_info.data_is_empty() {
+            create_pda_with_space(
+                &[
+                    b"gem_box".as_ref(),
+                    vault_key.as_ref(),
+                    gem_mint_info.key().as_ref(),
+                    &[bump_gem_box],
+                ],
+                gem_box_info,
+                TOKEN_ACCOUNT_LEN,
+                &token::ID,
+                &ctx.accounts.owner.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+            )?;
+            token::initialize_account(ctx.accounts.init_gem_box_ctx(gem_box_info, gem_mint_info))?;
+            new_gem_box_count.try_add_assign(1)?;
+        }
+
+        // same for the gdr
+        if gdr_info.data_is_empty() {
+            create_pda_with_space(
+                &[
+                    b"gem_deposit_receipt".as_ref(),
+                    vault_key.as_ref(),
+                    gem_mint_info.key().as_ref(),
+                    &[bump_gdr],
+                ],
+                gdr_info,
+                8 + std::mem::size_of::<GemDepositReceipt>(),
+                ctx.program_id,
+                &ctx.accounts.owner.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+            )?;
+
+            let disc = hash("account:GemDepositReceipt".as_bytes());
+            let mut gdr_raw = gdr_info.data.borrow_mut();
+            gdr_raw[..8].clone_from_slice(&disc.0[..8]);
+        }
+
+        let gem_box_amount = Account::<TokenAccount>::try_from(gem_box_info)?.amount;
+
+        // do the transfer
+        token::transfer(
+            ctx.accounts.transfer_ctx(gem_source_info, gem_box_info),
+            *amount,
+        )?;
+
+        // record the gdr
+        let mut gdr = Account::<GemDepositReceipt>::try_from(gdr_info)?;
+
+        gdr.vault = vault_key;
+        gdr.gem_box_address = gem_box_info.key();
+        gdr.gem_mint = gem_mint_info.key();
+        gdr.gem_count.try_add_assign(*amount)?;
+
+        // this check is semi-useless but won't hurt
+        if gdr.gem_count != gem_box_amount.try_add(*amount)? {
+            return Err(ErrorCode::AmountMismatch.into());
+        }
+
+        gdr.exit(ctx.program_id)?;
+
+        total_gem_count.try_add_assign(*amount)?;
+        total_rarity_points.try_add_assign(calc_rarity_points(gem_rarity_info, *amount)?)?;
+    }
+
+    // record totals in vault's state once, for the whole batch
+    let vault = &mut ctx.accounts.vault;
+    vault.gem_box_count.try_add_assign(new_gem_box_count)?;
+    vault.gem_count.try_add_assign(total_gem_count)?;
+    vault.rarity_points.try_add_assign(total_rarity_points)?;
+
+    msg!(
+        "{} gems deposited across {} gem boxes",
+        total_gem_count,
+        amounts.len()
+    );
+    Ok(())
+}
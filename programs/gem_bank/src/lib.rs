@@ -46,6 +46,14 @@ pub mod gem_bank {
         instructions::deposit_gem::handler(ctx, amount)
     }
 
+    pub fn deposit_gems_batch<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, DepositGemsBatch<'info>>,
+        _bump_auth: u8,
+        amounts: Vec<u64>,
+    ) -> ProgramResult {
+        instructions::deposit_gems_batch::handler(ctx, amounts)
+    }
+
     pub fn withdraw_gem(
         ctx: Context<WithdrawGem>,
         _bump_auth: u8,
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+/// read-only, anyone can call it - the result is only logged
+#[derive(Accounts)]
+pub struct CalcAprFunding<'info> {
+    // farm
+    pub farm: Box<Account<'info, Farm>>,
+}
+
+pub fn handler(
+    ctx: Context<CalcAprFunding>,
+    target_apr_bps: u64,
+    gem_price: u64,
+    reward_price: u64,
+    gems: Option<u64>,
+    duration_sec: u64,
+) -> ProgramResult {
+    // if not specified, assume every gem currently staked participates
+    // (!) gems, not rarity points - the rate is per gem
+    let gems = gems.unwrap_or(ctx.accounts.farm.gems_staked);
+
+    let funding = calc_apr_funding(target_apr_bps, gem_price, reward_price, gems, duration_sec)?;
+
+    msg!(
+        "rate ({} / gem / sec), required funding ({}) for {} gems over {} sec",
+        funding.rate,
+        funding.required_funding,
+        gems,
+        duration_sec
+    );
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Farm;
+
+/// read-only, anyone can call it - the result is only logged
+#[derive(Accounts)]
+pub struct CalcSolvencyRatio<'info> {
+    // farm
+    pub farm: Box<Account<'info, Farm>>,
+}
+
+pub fn handler(ctx: Context<CalcSolvencyRatio>) -> ProgramResult {
+    let farm = &ctx.accounts.farm;
+
+    let ratio_a = farm
+        .reward_a
+        .solvency_ratio_bps(farm.rarity_points_staked)?;
+    let ratio_b = farm
+        .reward_b
+        .solvency_ratio_bps(farm.rarity_points_staked)?;
+
+    msg!("solvency ratio ({} bps A) and ({} bps B)", ratio_a, ratio_b);
+    Ok(())
+}
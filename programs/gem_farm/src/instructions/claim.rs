@@ -3,7 +3,7 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token::{self, Mint, Token, TokenAccount, Transfer},
 };
-use gem_common::*;
+use gem_common::{errors::ErrorCode, *};
 
 use crate::state::*;
 
@@ -103,6 +103,19 @@ pub fn handler(ctx: Context<Claim>) -> ProgramResult {
         .reward_b
         .claim_reward(ctx.accounts.reward_b_pot.amount)?;
 
+    // a frozen pot would otherwise surface as an opaque token program error on transfer
+    // (!) only matters for pots we're about to pay out of - the other reward stays claimable
+    if (to_claim_a > 0 && ctx.accounts.reward_a_pot.is_frozen())
+        || (to_claim_b > 0 && ctx.accounts.reward_b_pot.is_frozen())
+    {
+        msg!("reward pot frozen, call report_frozen_escrow to flag the farm");
+        return Err(ErrorCode::EscrowFrozen.into());
+    }
+
+    // record claimed amounts at farm level
+    farm.reward_a.total_claimed.try_add_assign(to_claim_a)?;
+    farm.reward_b.total_claimed.try_add_assign(to_claim_b)?;
+
     // do the transfers
     if to_claim_a > 0 {
         token::transfer(
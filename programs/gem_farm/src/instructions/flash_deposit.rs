@@ -133,16 +133,8 @@ pub fn handler<'a, 'b, 'c, 'info>(
     farm.update_rewards(now_ts, Some(farmer), true)?;
 
     // stake extra gems
-    ctx.accounts.vault.reload()?;
     let extra_rarity = calc_rarity_points(&ctx.accounts.gem_rarity, amount)?;
-    farm.stake_extra_gems(
-        now_ts,
-        ctx.accounts.vault.gem_count,
-        ctx.accounts.vault.rarity_points,
-        amount,
-        extra_rarity,
-        farmer,
-    )?;
+    farm.stake_extra_gems(now_ts, amount, extra_rarity, farmer)?;
 
     // msg!("{} extra gems staked for {}", amount, farmer.key());
     Ok(())
@@ -10,7 +10,7 @@ use crate::state::*;
 const FEE_LAMPORTS: u64 = 5_000_000; // 0.005 SOL per farmer
 
 #[derive(Accounts)]
-#[instruction(bump_farmer: u8, bump_vault: u8)]
+#[instruction(bump_farmer: u8, bump_vault: u8, bump_profile: u8)]
 pub struct InitFarmer<'info> {
     // farm
     #[account(mut, has_one = bank)]
@@ -27,6 +27,14 @@ pub struct InitFarmer<'info> {
         space = 8 + std::mem::size_of::<Farmer>())]
     pub farmer: Box<Account<'info, Farmer>>,
     pub identity: Signer<'info>,
+    #[account(init, seeds = [
+            b"farmer_profile".as_ref(),
+            farmer.key().as_ref(),
+        ],
+        bump = bump_profile,
+        payer = payer,
+        space = 8 + std::mem::size_of::<FarmerProfile>())]
+    pub farmer_profile: Box<Account<'info, FarmerProfile>>,
 
     // cpi
     #[account(mut)]
@@ -71,8 +79,14 @@ impl<'info> InitFarmer<'info> {
     }
 }
 
-pub fn handler(ctx: Context<InitFarmer>, bump_vault: u8) -> ProgramResult {
+pub fn handler(
+    ctx: Context<InitFarmer>,
+    bump_vault: u8,
+    delegate: Option<Pubkey>,
+    referrer: Option<Pubkey>,
+) -> ProgramResult {
     // record new farmer details
+    // (!) the farmer starts out unstaked with all accruals zeroed - staking is a separate ix
     let farmer = &mut ctx.accounts.farmer;
 
     farmer.farm = ctx.accounts.farm.key();
@@ -81,6 +95,15 @@ pub fn handler(ctx: Context<InitFarmer>, bump_vault: u8) -> ProgramResult {
     farmer.reward_a.fixed_rate.promised_schedule = FixedRateSchedule::default(); //denom to 1
     farmer.reward_b.fixed_rate.promised_schedule = FixedRateSchedule::default(); //denom to 1
 
+    // record optional details
+    let profile = &mut ctx.accounts.farmer_profile;
+
+    profile.farmer = farmer.key();
+    profile.delegate = delegate.unwrap_or_default();
+    if let Some(referrer) = referrer {
+        profile.set_referrer(referrer, farmer.identity)?;
+    }
+
     // update farm
     let farm = &mut ctx.accounts.farm;
 
@@ -1,6 +1,8 @@
 pub mod add_rarities_to_bank;
 pub mod add_to_bank_whitelist;
 pub mod authorize_funder;
+pub mod calc_apr_funding;
+pub mod calc_solvency_ratio;
 pub mod cancel_reward;
 pub mod claim;
 pub mod deauthorize_funder;
@@ -9,17 +11,27 @@ pub mod fund_reward;
 pub mod init_farm;
 pub mod init_farmer;
 pub mod lock_reward;
+pub mod lock_reward_until;
 pub mod refresh_farmer;
 pub mod refresh_farmer_signed;
 pub mod remove_from_bank_whitelist;
+pub mod report_frozen_escrow;
+pub mod schedule_unstake;
+pub mod set_max_catchup;
 pub mod stake;
+pub mod sunset_reward;
 pub mod treasury_payout;
+pub mod treasury_token_payout;
 pub mod unstake;
 pub mod update_farm;
+pub mod update_farmer;
+pub mod withdraw_unlocked_tranche;
 
 pub use add_rarities_to_bank::*;
 pub use add_to_bank_whitelist::*;
 pub use authorize_funder::*;
+pub use calc_apr_funding::*;
+pub use calc_solvency_ratio::*;
 pub use cancel_reward::*;
 pub use claim::*;
 pub use deauthorize_funder::*;
@@ -28,13 +40,21 @@ pub use fund_reward::*;
 pub use init_farm::*;
 pub use init_farmer::*;
 pub use lock_reward::*;
+pub use lock_reward_until::*;
 pub use refresh_farmer::*;
 pub use refresh_farmer_signed::*;
 pub use remove_from_bank_whitelist::*;
+pub use report_frozen_escrow::*;
+pub use schedule_unstake::*;
+pub use set_max_catchup::*;
 pub use stake::*;
+pub use sunset_reward::*;
 pub use treasury_payout::*;
+pub use treasury_token_payout::*;
 pub use unstake::*;
 pub use update_farm::*;
+pub use update_farmer::*;
+pub use withdraw_unlocked_tranche::*;
 
 // have to duplicate or this won't show up in IDL
 use anchor_lang::prelude::*;
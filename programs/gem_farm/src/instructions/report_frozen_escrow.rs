@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::state::*;
+
+/// anyone can call it - a failed claim can't persist the flag itself, so this does it instead
+#[derive(Accounts)]
+pub struct ReportFrozenEscrow<'info> {
+    // farm
+    #[account(mut)]
+    pub farm: Box<Account<'info, Farm>>,
+
+    // reward pots
+    #[account(address = farm.reward_a.reward_pot)]
+    pub reward_a_pot: Box<Account<'info, TokenAccount>>,
+    #[account(address = farm.reward_b.reward_pot)]
+    pub reward_b_pot: Box<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(ctx: Context<ReportFrozenEscrow>) -> ProgramResult {
+    let frozen = ctx.accounts.reward_a_pot.is_frozen() || ctx.accounts.reward_b_pot.is_frozen();
+
+    // raised while frozen, cleared again once thawed
+    let farm = &mut ctx.accounts.farm;
+    let mut flags = Farm::read_flags(farm.flags)?;
+    flags.set(FarmFlags::ESCROW_FROZEN, frozen);
+    farm.reset_flags(flags);
+
+    msg!("escrow frozen: {}", frozen);
+    Ok(())
+}
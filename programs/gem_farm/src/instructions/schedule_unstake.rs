@@ -0,0 +1,132 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+use gem_bank::{
+    self,
+    instructions::calc_rarity_points,
+    state::{GemDepositReceipt, Vault},
+};
+use gem_common::{errors::ErrorCode, *};
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_treasury: u8, bump_farmer: u8, bump_schedule: u8)]
+pub struct ScheduleUnstake<'info> {
+    // farm
+    #[account(mut, has_one = farm_treasury, has_one = bank)]
+    pub farm: Box<Account<'info, Farm>>,
+    #[account(mut, seeds = [b"treasury".as_ref(), farm.key().as_ref()], bump = bump_treasury)]
+    pub farm_treasury: AccountInfo<'info>,
+
+    // farmer
+    #[account(mut, has_one = farm, has_one = identity, has_one = vault,
+        seeds = [
+            b"farmer".as_ref(),
+            farm.key().as_ref(),
+            identity.key().as_ref(),
+        ],
+        bump = bump_farmer)]
+    pub farmer: Box<Account<'info, Farmer>>,
+    #[account(mut)]
+    pub identity: Signer<'info>,
+    #[account(init_if_needed,
+        seeds = [
+            b"unstake_schedule".as_ref(),
+            farmer.key().as_ref(),
+        ],
+        bump = bump_schedule,
+        payer = identity,
+        space = 8 + std::mem::size_of::<UnstakeSchedule>())]
+    pub unstake_schedule: Box<Account<'info, UnstakeSchedule>>,
+
+    // bank
+    pub bank: AccountInfo<'info>,
+    pub vault: Box<Account<'info, Vault>>,
+    // owned by the bank program, so has_one is enough to tie it to the farmer's vault
+    #[account(has_one = vault)]
+    pub gem_deposit_receipt: Box<Account<'info, GemDepositReceipt>>,
+    // verified in the handler, lives under the bank program
+    pub gem_rarity: AccountInfo<'info>,
+
+    // misc
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ScheduleUnstake<'info> {
+    fn pay_treasury(&self, lamports: u64) -> ProgramResult {
+        invoke(
+            &system_instruction::transfer(self.identity.key, self.farm_treasury.key, lamports),
+            &[
+                self.identity.to_account_info(),
+                self.farm_treasury.clone(),
+                self.system_program.to_account_info(),
+            ],
+        )
+    }
+}
+
+pub fn handler(
+    ctx: Context<ScheduleUnstake>,
+    bump_rarity: u8,
+    gems: u64,
+    unlock_ts: u64,
+) -> ProgramResult {
+    let gdr = &ctx.accounts.gem_deposit_receipt;
+
+    // verify the rarity PDA belongs to this bank / mint
+    let rarity_pda = Pubkey::create_program_address(
+        &[
+            b"gem_rarity".as_ref(),
+            ctx.accounts.bank.key().as_ref(),
+            gdr.gem_mint.as_ref(),
+            &[bump_rarity],
+        ],
+        &gem_bank::ID,
+    )
+    .map_err(|_| ErrorCode::InvalidParameter)?;
+    if rarity_pda != ctx.accounts.gem_rarity.key() {
+        return Err(ErrorCode::InvalidParameter.into());
+    }
+
+    // tranches left over from an earlier staking session went through cooldown with everything else
+    let farmer_key = ctx.accounts.farmer.key();
+    let schedule = &mut ctx.accounts.unstake_schedule;
+    if !schedule.is_current(&ctx.accounts.farmer, ctx.accounts.vault.gem_count)? {
+        schedule.reset();
+    }
+    schedule.farmer = farmer_key;
+
+    // can't schedule more of a mint than is actually sitting in the vault
+    let already_scheduled = schedule.scheduled_gems(Some(gdr.gem_mint))?;
+    if already_scheduled.try_add(gems)? > gdr.gem_count {
+        return Err(ErrorCode::InvalidParameter.into());
+    }
+
+    let tranche = UnstakeTranche {
+        gem_mint: gdr.gem_mint,
+        gems,
+        rarity_points: calc_rarity_points(&ctx.accounts.gem_rarity, gems)?,
+        unlock_ts,
+    };
+
+    // each tranche is an unstake of its own, so it's charged the same fee
+    let fee = ctx.accounts.farm.config.unstaking_fee_lamp;
+    if fee > 0 {
+        ctx.accounts.pay_treasury(fee)?
+    }
+
+    // update accrued rewards BEFORE we decrement the stake
+    let farm = &mut ctx.accounts.farm;
+    let farmer = &mut ctx.accounts.farmer;
+    let schedule = &mut ctx.accounts.unstake_schedule;
+    let now_ts = now_ts()?;
+
+    farm.update_rewards(now_ts, Some(farmer), true)?;
+
+    farm.schedule_unstake(now_ts, tranche, farmer, schedule)?;
+
+    msg!("{} gems scheduled to unlock at {}", gems, unlock_ts);
+    Ok(())
+}
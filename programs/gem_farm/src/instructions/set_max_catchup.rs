@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetMaxCatchup<'info> {
+    // farm
+    #[account(mut, has_one = farm_manager)]
+    pub farm: Box<Account<'info, Farm>>,
+    pub farm_manager: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetMaxCatchup>, max_catchup_per_call: u64) -> ProgramResult {
+    let farm = &mut ctx.accounts.farm;
+
+    farm.max_catchup_per_call = max_catchup_per_call;
+
+    msg!("max catch-up per call set to {}", max_catchup_per_call);
+    Ok(())
+}
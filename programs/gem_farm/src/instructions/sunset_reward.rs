@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+use gem_common::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_auth: u8, bump_treasury: u8, bump_pot: u8)]
+pub struct SunsetReward<'info> {
+    // farm
+    #[account(mut, has_one = farm_manager, has_one = farm_authority, has_one = farm_treasury)]
+    pub farm: Box<Account<'info, Farm>>,
+    #[account(mut)]
+    pub farm_manager: Signer<'info>,
+    #[account(seeds = [farm.key().as_ref()], bump = bump_auth)]
+    pub farm_authority: AccountInfo<'info>,
+    #[account(seeds = [b"treasury".as_ref(), farm.key().as_ref()], bump = bump_treasury)]
+    pub farm_treasury: AccountInfo<'info>,
+
+    // reward
+    #[account(mut, seeds = [
+            b"reward_pot".as_ref(),
+            farm.key().as_ref(),
+            reward_mint.key().as_ref(),
+        ],
+        bump = bump_pot)]
+    pub reward_pot: Box<Account<'info, TokenAccount>>,
+    // pending funding is refunded same as with cancellation
+    #[account(init_if_needed,
+        associated_token::mint = reward_mint,
+        associated_token::authority = receiver,
+        payer = farm_manager)]
+    pub reward_destination: Box<Account<'info, TokenAccount>>,
+    // but the dust on top can only go to the farm's treasury
+    #[account(init_if_needed,
+        associated_token::mint = reward_mint,
+        associated_token::authority = farm_treasury,
+        payer = farm_manager)]
+    pub treasury_destination: Box<Account<'info, TokenAccount>>,
+    pub reward_mint: Box<Account<'info, Mint>>,
+    #[account(mut)]
+    pub receiver: AccountInfo<'info>,
+
+    // misc
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> SunsetReward<'info> {
+    fn transfer_ctx(
+        &self,
+        to: &AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reward_pot.to_account_info(),
+                to: to.clone(),
+                authority: self.farm_authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<SunsetReward>) -> ProgramResult {
+    // update existing rewards
+    let farm = &mut ctx.accounts.farm;
+    let now_ts = now_ts()?;
+
+    farm.update_rewards(now_ts, None, true)?;
+
+    // calculate the sweep (pending funding + rounding dust) while closing the books
+    let (refund_amount, dust_amount) = farm.sunset_reward_by_mint(
+        now_ts,
+        ctx.accounts.reward_mint.key(),
+        ctx.accounts.reward_pot.amount,
+    )?;
+
+    // do the transfers
+    if refund_amount > 0 {
+        let to = ctx.accounts.reward_destination.to_account_info();
+        token::transfer(
+            ctx.accounts
+                .transfer_ctx(&to)
+                .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+            refund_amount,
+        )?;
+    }
+
+    if dust_amount > 0 {
+        let to = ctx.accounts.treasury_destination.to_account_info();
+        token::transfer(
+            ctx.accounts
+                .transfer_ctx(&to)
+                .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+            dust_amount,
+        )?;
+    }
+
+    msg!(
+        "{} reward sunset, {} tokens refunded, {} tokens swept to treasury",
+        ctx.accounts.reward_mint.key(),
+        refund_amount,
+        dust_amount,
+    );
+    Ok(())
+}
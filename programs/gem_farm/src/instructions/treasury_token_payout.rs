@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_treasury: u8)]
+pub struct TreasuryTokenPayout<'info> {
+    // farm
+    #[account(has_one = farm_manager, has_one = farm_treasury)]
+    pub farm: Box<Account<'info, Farm>>,
+    #[account(mut)]
+    pub farm_manager: Signer<'info>,
+    #[account(seeds = [b"treasury".as_ref(), farm.key().as_ref()], bump = bump_treasury)]
+    pub farm_treasury: AccountInfo<'info>,
+
+    // tokens (eg reward dust swept at sunset)
+    #[account(mut,
+        associated_token::mint = mint,
+        associated_token::authority = farm_treasury)]
+    pub treasury_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(init_if_needed,
+        associated_token::mint = mint,
+        associated_token::authority = receiver,
+        payer = farm_manager)]
+    pub destination: Box<Account<'info, TokenAccount>>,
+    pub mint: Box<Account<'info, Mint>>,
+    #[account(mut)]
+    pub receiver: AccountInfo<'info>,
+
+    // misc
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> TreasuryTokenPayout<'info> {
+    fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.treasury_token_account.to_account_info(),
+                to: self.destination.to_account_info(),
+                authority: self.farm_treasury.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<TreasuryTokenPayout>, bump: u8, amount: u64) -> ProgramResult {
+    let farm_key = ctx.accounts.farm.key();
+
+    token::transfer(
+        ctx.accounts.transfer_ctx().with_signer(&[&[
+            b"treasury".as_ref(),
+            farm_key.as_ref(),
+            &[bump],
+        ]]),
+        amount,
+    )?;
+
+    msg!(
+        "{} {} tokens paid out from treasury",
+        amount,
+        ctx.accounts.mint.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump: u8, bump_profile: u8)]
+pub struct UpdateFarmer<'info> {
+    // farm
+    pub farm: Box<Account<'info, Farm>>,
+
+    // farmer
+    #[account(has_one = farm, has_one = identity, seeds = [
+            b"farmer".as_ref(),
+            farm.key().as_ref(),
+            identity.key().as_ref(),
+        ],
+        bump = bump)]
+    pub farmer: Box<Account<'info, Farmer>>,
+    #[account(mut)] //payer
+    pub identity: Signer<'info>,
+    // farmers initialized before profiles existed won't have one yet
+    #[account(init_if_needed, seeds = [
+            b"farmer_profile".as_ref(),
+            farmer.key().as_ref(),
+        ],
+        bump = bump_profile,
+        payer = identity,
+        space = 8 + std::mem::size_of::<FarmerProfile>())]
+    pub farmer_profile: Box<Account<'info, FarmerProfile>>,
+
+    // misc
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateFarmer>,
+    delegate: Option<Pubkey>,
+    referrer: Option<Pubkey>,
+) -> ProgramResult {
+    let profile = &mut ctx.accounts.farmer_profile;
+
+    profile.farmer = ctx.accounts.farmer.key();
+
+    if let Some(delegate) = delegate {
+        profile.delegate = delegate;
+    }
+
+    if let Some(referrer) = referrer {
+        profile.set_referrer(referrer, ctx.accounts.identity.key())?;
+    }
+
+    msg!("updated farmer {}", profile.farmer);
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token},
+};
+use gem_bank::{
+    self,
+    cpi::accounts::{SetVaultLock, WithdrawGem},
+    program::GemBank,
+    state::{Bank, Vault},
+};
+use gem_common::{errors::ErrorCode, *};
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_auth: u8, bump_farmer: u8, bump_schedule: u8)]
+pub struct WithdrawUnlockedTranche<'info> {
+    // farm
+    #[account(has_one = farm_authority, has_one = bank)]
+    pub farm: Box<Account<'info, Farm>>,
+    #[account(seeds = [farm.key().as_ref()], bump = bump_auth)]
+    pub farm_authority: AccountInfo<'info>,
+
+    // farmer
+    #[account(mut, has_one = farm, has_one = identity, has_one = vault,
+        seeds = [
+            b"farmer".as_ref(),
+            farm.key().as_ref(),
+            identity.key().as_ref(),
+        ],
+        bump = bump_farmer)]
+    pub farmer: Box<Account<'info, Farmer>>,
+    #[account(mut)]
+    pub identity: Signer<'info>,
+    #[account(mut, has_one = farmer,
+        seeds = [
+            b"unstake_schedule".as_ref(),
+            farmer.key().as_ref(),
+        ],
+        bump = bump_schedule)]
+    pub unstake_schedule: Box<Account<'info, UnstakeSchedule>>,
+
+    // cpi
+    #[account(constraint = bank.bank_manager == farm_authority.key())]
+    pub bank: Box<Account<'info, Bank>>,
+    #[account(mut)]
+    pub vault: Box<Account<'info, Vault>>,
+    pub vault_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub gem_box: AccountInfo<'info>,
+    #[account(mut)]
+    pub gem_deposit_receipt: AccountInfo<'info>,
+    // trying to deserialize here leads to errors (doesn't exist yet)
+    #[account(mut)]
+    pub gem_destination: AccountInfo<'info>,
+    pub gem_mint: Box<Account<'info, Mint>>,
+    pub gem_rarity: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub gem_bank: Program<'info, GemBank>,
+}
+
+impl<'info> WithdrawUnlockedTranche<'info> {
+    fn set_lock_vault_ctx(&self) -> CpiContext<'_, '_, '_, 'info, SetVaultLock<'info>> {
+        CpiContext::new(
+            self.gem_bank.to_account_info(),
+            SetVaultLock {
+                bank: self.bank.to_account_info(),
+                vault: self.vault.to_account_info(),
+                bank_manager: self.farm_authority.clone(),
+            },
+        )
+    }
+
+    fn withdraw_gem_ctx(&self) -> CpiContext<'_, '_, '_, 'info, WithdrawGem<'info>> {
+        CpiContext::new(
+            self.gem_bank.to_account_info(),
+            WithdrawGem {
+                bank: self.bank.to_account_info(),
+                vault: self.vault.to_account_info(),
+                owner: self.identity.to_account_info(),
+                authority: self.vault_authority.clone(),
+                gem_box: self.gem_box.clone(),
+                gem_deposit_receipt: self.gem_deposit_receipt.clone(),
+                gem_destination: self.gem_destination.clone(),
+                gem_mint: self.gem_mint.to_account_info(),
+                gem_rarity: self.gem_rarity.clone(),
+                receiver: self.identity.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                associated_token_program: self.associated_token_program.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                rent: self.rent.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(
+    ctx: Context<WithdrawUnlockedTranche>,
+    bump_vault_auth: u8,
+    bump_gem_box: u8,
+    bump_gdr: u8,
+    bump_rarity: u8,
+    index: u8,
+) -> ProgramResult {
+    // after a full unstake the tranches cool down with the rest of the vault instead
+    let schedule = &mut ctx.accounts.unstake_schedule;
+    if !schedule.is_current(&ctx.accounts.farmer, ctx.accounts.vault.gem_count)? {
+        msg!("unstake schedule is from an earlier staking session");
+        return Err(ErrorCode::InvalidParameter.into());
+    }
+
+    // free up the tranche - gems in it already stopped accruing when it was scheduled
+    let tranche = schedule.take_unlocked_tranche(index as usize, now_ts()?)?;
+
+    if tranche.gem_mint != ctx.accounts.gem_mint.key() {
+        return Err(ErrorCode::InvalidParameter.into());
+    }
+
+    // the rest of the stake stays put, so the vault is only unlocked for the duration of the withdrawal
+    gem_bank::cpi::set_vault_lock(
+        ctx.accounts
+            .set_lock_vault_ctx()
+            .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+        false,
+    )?;
+
+    gem_bank::cpi::withdraw_gem(
+        ctx.accounts.withdraw_gem_ctx(),
+        bump_vault_auth,
+        bump_gem_box,
+        bump_gdr,
+        bump_rarity,
+        tranche.gems,
+    )?;
+
+    gem_bank::cpi::set_vault_lock(
+        ctx.accounts
+            .set_lock_vault_ctx()
+            .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+        true,
+    )?;
+
+    msg!("{} gems withdrawn from tranche {}", tranche.gems, index);
+    Ok(())
+}
@@ -37,6 +37,14 @@ pub mod gem_farm {
         instructions::update_farm::handler(ctx, config, manager)
     }
 
+    pub fn set_max_catchup(
+        ctx: Context<SetMaxCatchup>,
+        max_catchup_per_call: u64,
+    ) -> ProgramResult {
+        msg!("set max catchup");
+        instructions::set_max_catchup::handler(ctx, max_catchup_per_call)
+    }
+
     pub fn payout_from_treasury(
         ctx: Context<TreasuryPayout>,
         _bump_auth: u8,
@@ -47,6 +55,15 @@ pub mod gem_farm {
         instructions::treasury_payout::handler(ctx, bump_treasury, lamports)
     }
 
+    pub fn payout_tokens_from_treasury(
+        ctx: Context<TreasuryTokenPayout>,
+        bump_treasury: u8,
+        amount: u64,
+    ) -> ProgramResult {
+        msg!("payout tokens");
+        instructions::treasury_token_payout::handler(ctx, bump_treasury, amount)
+    }
+
     pub fn add_to_bank_whitelist(
         ctx: Context<AddToBankWhitelist>,
         _bump_auth: u8,
@@ -72,9 +89,23 @@ pub mod gem_farm {
         ctx: Context<InitFarmer>,
         _bump_farmer: u8,
         bump_vault: u8,
+        _bump_profile: u8,
+        delegate: Option<Pubkey>,
+        referrer: Option<Pubkey>,
     ) -> ProgramResult {
         msg!("init farmer");
-        instructions::init_farmer::handler(ctx, bump_vault)
+        instructions::init_farmer::handler(ctx, bump_vault, delegate, referrer)
+    }
+
+    pub fn update_farmer(
+        ctx: Context<UpdateFarmer>,
+        _bump: u8,
+        _bump_profile: u8,
+        delegate: Option<Pubkey>,
+        referrer: Option<Pubkey>,
+    ) -> ProgramResult {
+        msg!("update farmer");
+        instructions::update_farmer::handler(ctx, delegate, referrer)
     }
 
     pub fn stake(ctx: Context<Stake>, _bump_auth: u8, _bump_farmer: u8) -> ProgramResult {
@@ -123,6 +154,41 @@ pub mod gem_farm {
         )
     }
 
+    pub fn schedule_unstake(
+        ctx: Context<ScheduleUnstake>,
+        _bump_treasury: u8,
+        _bump_farmer: u8,
+        _bump_schedule: u8,
+        bump_rarity: u8,
+        gems: u64,
+        unlock_ts: u64,
+    ) -> ProgramResult {
+        msg!("schedule unstake");
+        instructions::schedule_unstake::handler(ctx, bump_rarity, gems, unlock_ts)
+    }
+
+    pub fn withdraw_unlocked_tranche(
+        ctx: Context<WithdrawUnlockedTranche>,
+        _bump_auth: u8,
+        _bump_farmer: u8,
+        _bump_schedule: u8,
+        bump_vault_auth: u8,
+        bump_gem_box: u8,
+        bump_gdr: u8,
+        bump_rarity: u8,
+        index: u8,
+    ) -> ProgramResult {
+        msg!("withdraw unlocked tranche");
+        instructions::withdraw_unlocked_tranche::handler(
+            ctx,
+            bump_vault_auth,
+            bump_gem_box,
+            bump_gdr,
+            bump_rarity,
+            index,
+        )
+    }
+
     pub fn refresh_farmer(ctx: Context<RefreshFarmer>, _bump: u8) -> ProgramResult {
         msg!("refresh farmer");
         instructions::refresh_farmer::handler(ctx)
@@ -174,11 +240,55 @@ pub mod gem_farm {
         instructions::cancel_reward::handler(ctx)
     }
 
+    pub fn sunset_reward(
+        ctx: Context<SunsetReward>,
+        _bump_auth: u8,
+        _bump_treasury: u8,
+        _bump_pot: u8,
+    ) -> ProgramResult {
+        msg!("sunset reward");
+        instructions::sunset_reward::handler(ctx)
+    }
+
     pub fn lock_reward(ctx: Context<LockReward>) -> ProgramResult {
         msg!("lock reward");
         instructions::lock_reward::handler(ctx)
     }
 
+    pub fn lock_reward_until(ctx: Context<LockRewardUntil>, lock_end_ts: u64) -> ProgramResult {
+        msg!("lock reward until");
+        instructions::lock_reward_until::handler(ctx, lock_end_ts)
+    }
+
+    pub fn calc_solvency_ratio(ctx: Context<CalcSolvencyRatio>) -> ProgramResult {
+        msg!("calc solvency ratio");
+        instructions::calc_solvency_ratio::handler(ctx)
+    }
+
+    pub fn calc_apr_funding(
+        ctx: Context<CalcAprFunding>,
+        target_apr_bps: u64,
+        gem_price: u64,
+        reward_price: u64,
+        gems: Option<u64>,
+        duration_sec: u64,
+    ) -> ProgramResult {
+        msg!("calc apr funding");
+        instructions::calc_apr_funding::handler(
+            ctx,
+            target_apr_bps,
+            gem_price,
+            reward_price,
+            gems,
+            duration_sec,
+        )
+    }
+
+    pub fn report_frozen_escrow(ctx: Context<ReportFrozenEscrow>) -> ProgramResult {
+        msg!("report frozen escrow");
+        instructions::report_frozen_escrow::handler(ctx)
+    }
+
     // --------------------------------------- rarities
 
     pub fn add_rarities_to_bank<'a, 'b, 'c, 'info>(
@@ -18,7 +18,6 @@ use gem_common::{errors::ErrorCode, TryAdd, TryDiv, TryMul, TryPow, TryRem, TryS
 
 const ONE: u128 = 1_000_000_000_000_000;
 const PRECISION: i32 = 15;
-const U64_MAX: u128 = u64::MAX as u128;
 
 #[derive(
     Default, Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, AnchorSerialize, AnchorDeserialize,
@@ -40,11 +39,10 @@ impl Number128 {
         }
 
         let target_value = self.n / prec_value;
-        if target_value > U64_MAX {
-            panic!("cannot convert to u64 due to overflow");
-        }
-
-        u64::try_from(target_value).map_err(|_| ErrorCode::ArithmeticError.into())
+        u64::try_from(target_value).map_err(|_| {
+            msg!("cannot convert {} to u64 due to overflow", self);
+            ProgramError::from(ErrorCode::ArithmeticError)
+        })
     }
 
     pub fn as_u64_ceil(&self, exponent: impl Into<i32>) -> Result<u64, ProgramError> {
@@ -56,11 +54,10 @@ impl Number128 {
         }
 
         let target_value = (prec_value - 1 + self.n) / prec_value;
-        if target_value > U64_MAX {
-            panic!("cannot convert to u64 due to overflow");
-        }
-
-        u64::try_from(target_value).map_err(|_| ErrorCode::ArithmeticError.into())
+        u64::try_from(target_value).map_err(|_| {
+            msg!("cannot convert {} to u64 due to overflow", self);
+            ProgramError::from(ErrorCode::ArithmeticError)
+        })
     }
 
     pub fn from_decimal(value: impl Into<u128>, exponent: impl Into<i32>) -> Self {
@@ -76,6 +73,29 @@ impl Number128 {
         }
     }
 
+    /// unlike try_mul, rhs isn't scaled up by ONE first - leaves a lot more room before overflowing
+    pub fn try_mul_int(self, rhs: u64) -> Result<Self, ProgramError> {
+        let result = self.n.checked_mul(rhs.into()).ok_or_else(|| {
+            msg!("tried multiplying {} and {}", self, rhs);
+            ProgramError::from(ErrorCode::ArithmeticError)
+        })?;
+        Ok(Self { n: result })
+    }
+
+    /// unlike try_div, lhs isn't scaled up by ONE first - leaves a lot more room before overflowing
+    pub fn try_div_int(self, rhs: u64) -> Result<Self, ProgramError> {
+        let result = self.n.checked_div(rhs.into()).ok_or_else(|| {
+            msg!("tried dividing {} by {}", self, rhs);
+            ProgramError::from(ErrorCode::ArithmeticError)
+        })?;
+        Ok(Self { n: result })
+    }
+
+    /// bps = basis points, 10000 = 1x
+    pub fn try_mul_bps(self, bps: u64) -> Result<Self, ProgramError> {
+        self.try_mul_int(bps)?.try_div_int(10_000)
+    }
+
     fn ten_pow(exponent: u32) -> u128 {
         let value: u64 = match exponent {
             16 => 10_000_000_000_000_000,
@@ -297,6 +317,46 @@ mod tests2 {
         )
     }
 
+    #[test]
+    fn multiply_divide_by_int() {
+        let x = Number128::from_decimal(15_u64, -1_i32);
+        assert_eq!(
+            Number128::from_decimal(45_u64, -1_i32),
+            x.try_mul_int(3).unwrap()
+        );
+        assert_eq!(
+            Number128::from_decimal(5_u64, -1_i32),
+            x.try_div_int(3).unwrap()
+        );
+
+        // wouldn't fit if rhs got scaled up by ONE
+        let big = Number128::from(u64::MAX);
+        assert!(big.try_mul(Number128::from(10_000u64)).is_err());
+        assert_eq!(big, big.try_mul_bps(10_000).unwrap());
+    }
+
+    #[test]
+    fn as_u64_overflow() {
+        assert_eq!(u64::MAX, Number128::from(u64::MAX).as_u64(0).unwrap());
+        assert_eq!(u64::MAX, Number128::from(u64::MAX).as_u64_ceil(0).unwrap());
+
+        let too_big = Number128::from(u64::MAX).try_mul_int(2).unwrap();
+        assert!(too_big.as_u64(0).is_err());
+        assert!(too_big.as_u64_ceil(0).is_err());
+    }
+
+    #[test]
+    fn multiply_by_bps() {
+        assert_eq!(
+            Number128::from_decimal(15_u64, 0_i32),
+            Number128::from(10u64).try_mul_bps(15_000).unwrap()
+        );
+        assert_eq!(
+            Number128::from_decimal(25_u64, -1_i32),
+            Number128::from(10u64).try_mul_bps(2_500).unwrap()
+        );
+    }
+
     #[test]
     fn ceil_gt_one() {
         assert_eq!(
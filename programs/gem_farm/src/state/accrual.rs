@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use gem_common::errors::ErrorCode;
+
+use crate::number128::Number128;
+
+/// 1x, in basis points
+pub const NEUTRAL_BPS: u64 = 10_000;
+
+/// per-farmer boosts stacked on top of rarity, each in basis points (10000 = 1x)
+/// none of these are configurable yet, so they're always neutral - features that introduce them
+/// should plug their bps in here rather than scaling the reward themselves (else double-applied)
+/// (!) farm-level accrual doesn't apply them yet, so combined they can't go above 1x - see below
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AccrualMultipliers {
+    pub streak_bps: u64,
+
+    pub loyalty_bps: u64,
+
+    pub calendar_bps: u64,
+}
+
+impl Default for AccrualMultipliers {
+    fn default() -> Self {
+        Self {
+            streak_bps: NEUTRAL_BPS,
+            loyalty_bps: NEUTRAL_BPS,
+            calendar_bps: NEUTRAL_BPS,
+        }
+    }
+}
+
+impl AccrualMultipliers {
+    pub fn combined(&self) -> Result<Number128, ProgramError> {
+        Number128::ONE
+            .try_mul_bps(self.streak_bps)?
+            .try_mul_bps(self.loyalty_bps)?
+            .try_mul_bps(self.calendar_bps)
+    }
+}
+
+/// the ONLY place multipliers get applied, always in this order:
+///   base reward (per rarity point) -> rarity points -> streak -> loyalty -> calendar
+/// each bps step rounds down at Number128's precision, converting to tokens is left to the caller
+pub fn effective_accrual(
+    base_per_rarity_point: Number128,
+    rarity_points: u64,
+    multipliers: &AccrualMultipliers,
+) -> Result<Number128, ProgramError> {
+    // what the farm reserves / counts as accrued is base * rarity points, with no multipliers
+    // until that changes, a boost would credit farmers more than the farm has set aside
+    if multipliers.combined()? > Number128::ONE {
+        return Err(ErrorCode::InvalidParameter.into());
+    }
+
+    base_per_rarity_point
+        .try_mul_int(rarity_points)?
+        .try_mul_bps(multipliers.streak_bps)?
+        .try_mul_bps(multipliers.loyalty_bps)?
+        .try_mul_bps(multipliers.calendar_bps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effective_accrual_neutral() {
+        let base = Number128::from_decimal(125u64, -2i32); // 1.25
+
+        assert_eq!(
+            Number128::from_decimal(125u64, 0i32),
+            effective_accrual(base, 100, &AccrualMultipliers::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_effective_accrual_is_product_of_factors() {
+        let base = Number128::from(8u64);
+        let multipliers = AccrualMultipliers {
+            streak_bps: 15_000,  // 1.5x
+            loyalty_bps: 12_500, // 1.25x
+            calendar_bps: 5_000, // 0.5x
+        };
+
+        // 8 * 10 * 1.5 * 1.25 * 0.5 = 75
+        assert_eq!(
+            Number128::from(75u64),
+            effective_accrual(base, 10, &multipliers).unwrap()
+        );
+
+        // same as applying each factor on its own, one after the other
+        let one_by_one = Number128::from(80u64)
+            .try_mul_bps(15_000)
+            .unwrap()
+            .try_mul_bps(12_500)
+            .unwrap()
+            .try_mul_bps(5_000)
+            .unwrap();
+        assert_eq!(
+            one_by_one,
+            effective_accrual(base, 10, &multipliers).unwrap()
+        );
+
+        // and each factor only counts once - 1 point, loyalty neutral
+        let no_loyalty = AccrualMultipliers {
+            streak_bps: 15_000,
+            calendar_bps: 5_000,
+            ..AccrualMultipliers::default()
+        };
+        assert_eq!(
+            Number128::from(6u64),
+            effective_accrual(base, 1, &no_loyalty).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_effective_accrual_rejects_boosts() {
+        let base = Number128::from(8u64);
+
+        // farm-level accrual doesn't know about multipliers - anything above 1x is insolvent
+        let streak_only = AccrualMultipliers {
+            streak_bps: 15_000,
+            ..AccrualMultipliers::default()
+        };
+        assert!(effective_accrual(base, 1, &streak_only).is_err());
+
+        let boosted = AccrualMultipliers {
+            streak_bps: 10_001,
+            loyalty_bps: 10_000,
+            calendar_bps: 10_000,
+        };
+        assert!(effective_accrual(base, 1, &boosted).is_err());
+
+        // scaling down is fine, the difference just stays in the pot
+        let reduced = AccrualMultipliers {
+            streak_bps: 20_000,
+            loyalty_bps: 5_000,
+            calendar_bps: 9_000,
+        };
+        assert_eq!(
+            Number128::from_decimal(72u64, -1i32),
+            effective_accrual(base, 1, &reduced).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_effective_accrual_zero_points() {
+        let multipliers = AccrualMultipliers {
+            streak_bps: 5_000,
+            loyalty_bps: 5_000,
+            calendar_bps: 5_000,
+        };
+
+        assert_eq!(
+            Number128::ZERO,
+            effective_accrual(Number128::from(1000u64), 0, &multipliers).unwrap()
+        );
+    }
+}
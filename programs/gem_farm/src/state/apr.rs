@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use gem_common::{errors::ErrorCode, *};
+
+use crate::number128::Number128;
+
+pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+/// what a funder has to commit to hit a target APR
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AprFunding {
+    /// reward tokens / gem / sec
+    pub rate: Number128,
+
+    /// reward tokens needed to pay out the rate to every participating gem for the whole duration
+    pub required_funding: u64,
+}
+
+/// prices can be in any unit (lamports, usdc..) as long as it's the same one for both,
+/// and both are quoted per smallest unit (1 gem, 1 base unit of the reward token)
+pub fn calc_apr_funding(
+    target_apr_bps: u64,
+    gem_price: u64,
+    reward_price: u64,
+    gems: u64,
+    duration_sec: u64,
+) -> Result<AprFunding, ProgramError> {
+    if gem_price == 0 || reward_price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+
+    // a year's worth of rewards per gem = apr * gem_price / reward_price tokens
+    let rate = Number128::from(gem_price)
+        .try_mul_bps(target_apr_bps)?
+        .try_div_int(reward_price)?
+        .try_div_int(SECONDS_PER_YEAR)?;
+
+    // done from the raw inputs rather than the (already rounded down) rate, so the only rounding
+    // happens once at the end - and it's up, so the pot is never short
+    let funding_value = (gem_price as u128)
+        .try_mul(target_apr_bps as u128)?
+        .try_mul(gems as u128)?
+        .try_mul(duration_sec as u128)?;
+    let reward_value = (reward_price as u128)
+        .try_mul(10_000)?
+        .try_mul(SECONDS_PER_YEAR as u128)?;
+
+    let required_funding = if funding_value == 0 {
+        0
+    } else {
+        funding_value.try_ceil_div(reward_value)?.try_cast()?
+    };
+
+    Ok(AprFunding {
+        rate,
+        required_funding,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calc_apr_funding_exact() {
+        // 100% apr on a gem worth 3_153_600_000 reward tokens = 3_153_600_000 / 31_536_000 = 100 / sec
+        let funding = calc_apr_funding(10_000, 3_153_600_000, 1, 10, 86_400).unwrap();
+
+        assert_eq!(Number128::from(100u64), funding.rate);
+        // 100 * 10 gems * 1 day
+        assert_eq!(86_400_000, funding.required_funding);
+    }
+
+    #[test]
+    fn test_calc_apr_funding_rounds_up() {
+        // 10% apr, gem worth 50 usdc, reward token worth 0.25 usdc (6 decimals)
+        // -> 5 usdc / gem / year = 20 tokens / gem / year
+        let funding = calc_apr_funding(1_000, 50_000_000, 250_000, 7, 2_592_000).unwrap();
+
+        // 20 / 31_536_000 = 0.000000634195839675...
+        assert_eq!(
+            Number128::from_decimal(634_195_839u64, -15i32),
+            funding.rate
+        );
+        // 20 * 7 gems * 30 days / 1 year = 11.5068..
+        assert_eq!(12, funding.required_funding);
+    }
+
+    #[test]
+    fn test_calc_apr_funding_nothing_to_fund() {
+        let funding = calc_apr_funding(1_000, 50_000_000, 250_000, 0, 2_592_000).unwrap();
+        assert_eq!(0, funding.required_funding);
+
+        let funding = calc_apr_funding(0, 50_000_000, 250_000, 7, 2_592_000).unwrap();
+        assert_eq!(Number128::ZERO, funding.rate);
+        assert_eq!(0, funding.required_funding);
+    }
+
+    #[test]
+    fn test_calc_apr_funding_zero_price() {
+        let err: ProgramError = ErrorCode::InvalidPrice.into();
+
+        assert_eq!(
+            err,
+            calc_apr_funding(1_000, 0, 250_000, 7, 86_400).unwrap_err()
+        );
+        assert_eq!(
+            err,
+            calc_apr_funding(1_000, 50_000_000, 0, 7, 86_400).unwrap_err()
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use gem_common::{errors::ErrorCode, *};
 
-use crate::state::*;
+use crate::{number128::Number128, state::*};
 
 pub const LATEST_FARM_VERSION: u16 = 0;
 
@@ -66,11 +66,45 @@ pub struct Farm {
 
     pub reward_b: FarmReward,
 
+    /// max reward (per reward type) credited to a farmer in a single update, 0 = no cap
+    /// anything above is kept pending and credited on the next update(s)
+    /// (!) kept out of FarmConfig so existing farms' layout doesn't shift
+    pub max_catchup_per_call: u64,
+
+    /// raised by the program itself, for operators to pick up (see FarmFlags)
+    pub flags: u32,
+
+    /// rewards whose total_credited_to_farmers is complete (see CreditTracking)
+    pub credit_tracking: u8,
+
     /// reserved for future updates, has to be /8
-    _reserved: [u8; 64],
+    _reserved: [u8; 51],
 }
 
 impl Farm {
+    pub fn read_flags(flags: u32) -> Result<FarmFlags, ProgramError> {
+        FarmFlags::from_bits(flags).ok_or(ErrorCode::InvalidParameter.into())
+    }
+
+    pub fn reset_flags(&mut self, flags: FarmFlags) {
+        self.flags = flags.bits();
+    }
+
+    /// total_credited_to_farmers only adds up if it's been tracked since before anything accrued,
+    /// so a reward is only marked while nothing has accrued to it yet
+    fn mark_credit_tracking(&mut self) {
+        let mut tracking = CreditTracking::from_bits_truncate(self.credit_tracking);
+
+        if self.reward_a.funds.total_accrued_to_stakers == 0 {
+            tracking.insert(CreditTracking::REWARD_A);
+        }
+        if self.reward_b.funds.total_accrued_to_stakers == 0 {
+            tracking.insert(CreditTracking::REWARD_B);
+        }
+
+        self.credit_tracking = tracking.bits();
+    }
+
     pub fn farm_seeds(&self) -> [&[u8]; 2] {
         [
             self.farm_authority_seed.as_ref(),
@@ -97,6 +131,15 @@ impl Farm {
         reward.lock_reward()
     }
 
+    pub fn lock_reward_until_by_mint(
+        &mut self,
+        reward_mint: Pubkey,
+        lock_end_ts: u64,
+    ) -> ProgramResult {
+        let reward = self.match_reward_by_mint(reward_mint)?;
+        reward.lock_reward_until(lock_end_ts)
+    }
+
     pub fn fund_reward_by_mint(
         &mut self,
         now_ts: u64,
@@ -108,6 +151,34 @@ impl Farm {
         reward.fund_reward_by_type(now_ts, variable_rate_config, fixed_rate_config)
     }
 
+    /// returns (refund_amount, dust_amount)
+    pub fn sunset_reward_by_mint(
+        &mut self,
+        now_ts: u64,
+        reward_mint: Pubkey,
+        pot_balance: u64,
+    ) -> Result<(u64, u64), ProgramError> {
+        // farmers get credited when they unstake, so with no one staked everyone is whole
+        if self.staked_farmer_count > 0 {
+            return Err(ErrorCode::FarmersStillStaked.into());
+        }
+
+        // rewards that accrued before total_credited_to_farmers existed have it understated,
+        // sweeping against it would take farmers' unclaimed rewards with it
+        let tracking = CreditTracking::from_bits_truncate(self.credit_tracking);
+        let required = if reward_mint == self.reward_a.reward_mint {
+            CreditTracking::REWARD_A
+        } else {
+            CreditTracking::REWARD_B
+        };
+        if !tracking.contains(required) {
+            return Err(ErrorCode::CreditsNotTracked.into());
+        }
+
+        let reward = self.match_reward_by_mint(reward_mint)?;
+        reward.sunset_reward(now_ts, pot_balance)
+    }
+
     pub fn cancel_reward_by_mint(
         &mut self,
         now_ts: u64,
@@ -120,21 +191,28 @@ impl Farm {
     pub fn update_rewards(
         &mut self,
         now_ts: u64,
-        mut farmer: Option<&mut Account<Farmer>>,
+        mut farmer: Option<&mut Farmer>,
         reenroll: bool, //relevant for fixed only
     ) -> ProgramResult {
+        let max_catchup = self.max_catchup_per_call;
+
+        self.mark_credit_tracking();
+
         // reward a
-        let (farmer_points_staked, farmer_reward_a) = match farmer {
+        let (farmer_gems_staked, farmer_points_staked, farmer_reward_a) = match farmer {
             Some(ref mut farmer) => (
+                Some(farmer.gems_staked),
                 Some(farmer.rarity_points_staked),
                 Some(&mut farmer.reward_a),
             ),
-            None => (None, None),
+            None => (None, None, None),
         };
+        let accrued_a_before = farmer_reward_a.as_ref().map(|r| r.accrued_reward);
 
         self.reward_a.update_accrued_reward_by_type(
             now_ts,
             self.rarity_points_staked,
+            farmer_gems_staked,
             farmer_points_staked,
             farmer_reward_a,
             reenroll,
@@ -145,14 +223,29 @@ impl Farm {
             Some(ref mut farmer) => Some(&mut farmer.reward_b),
             None => None,
         };
+        let accrued_b_before = farmer_reward_b.as_ref().map(|r| r.accrued_reward);
 
         self.reward_b.update_accrued_reward_by_type(
             now_ts,
             self.rarity_points_staked,
+            farmer_gems_staked,
             farmer_points_staked,
             farmer_reward_b,
             reenroll,
-        )
+        )?;
+
+        // cap how much of the accrual gets credited in one go, rest stays pending for next time
+        // (!) the farm side has already accounted for the full amount, so funds stay reserved
+        if let Some(farmer) = farmer {
+            if let Some(before) = accrued_a_before {
+                farmer.reward_a.apply_catchup_cap(before, max_catchup)?;
+            }
+            if let Some(before) = accrued_b_before {
+                farmer.reward_b.apply_catchup_cap(before, max_catchup)?;
+            }
+        }
+
+        Ok(())
     }
 
     pub fn begin_staking(
@@ -239,18 +332,21 @@ impl Farm {
     pub fn stake_extra_gems(
         &mut self,
         now_ts: u64,
-        gems_in_vault: u64,
-        rarity_points_in_vault: u64,
         extra_gems: u64,
         extra_rarity_points: u64,
         farmer: &mut Account<Farmer>,
     ) -> ProgramResult {
+        // the stake grows by exactly what was deposited - going off the vault's totals instead
+        // would also pull back in any gems scheduled for unstaking, which are still in there
+        let gems_staked = farmer.gems_staked.try_add(extra_gems)?;
+        let rarity_points_staked = farmer.rarity_points_staked.try_add(extra_rarity_points)?;
+
         // update farmer
         let (_previous_gems, previous_rarity_points) = farmer.begin_staking(
             self.config.min_staking_period_sec,
             now_ts,
-            gems_in_vault,
-            rarity_points_in_vault,
+            gems_staked,
+            rarity_points_staked,
         )?;
 
         // update farm
@@ -258,6 +354,36 @@ impl Farm {
         self.rarity_points_staked
             .try_add_assign(extra_rarity_points)?;
 
+        self.reenroll_fixed_rate_farmer(now_ts, previous_rarity_points, farmer)
+    }
+
+    pub fn schedule_unstake(
+        &mut self,
+        now_ts: u64,
+        tranche: UnstakeTranche,
+        farmer: &mut Account<Farmer>,
+        schedule: &mut UnstakeSchedule,
+    ) -> ProgramResult {
+        let previous_rarity_points = farmer.rarity_points_staked;
+
+        // update farmer
+        farmer.schedule_unstake(now_ts, self.config.cooldown_period_sec, tranche, schedule)?;
+
+        // update farm
+        self.gems_staked.try_sub_assign(tranche.gems)?;
+        self.rarity_points_staked
+            .try_sub_assign(tranche.rarity_points)?;
+
+        self.reenroll_fixed_rate_farmer(now_ts, previous_rarity_points, farmer)
+    }
+
+    /// for when a staked farmer's rarity points change w/o them unstaking
+    fn reenroll_fixed_rate_farmer(
+        &mut self,
+        now_ts: u64,
+        previous_rarity_points: u64,
+        farmer: &mut Account<Farmer>,
+    ) -> ProgramResult {
         // fixed-rate only - we need to do some extra book-keeping
         if self.reward_a.reward_type == RewardType::Fixed {
             // graduate with PREVIOUS rarity points count
@@ -299,6 +425,20 @@ impl Farm {
     }
 }
 
+bitflags::bitflags! {
+    pub struct FarmFlags: u32 {
+        /// one of the reward pots was frozen by its mint's freeze authority - claims will fail
+        const ESCROW_FROZEN = 1 << 0;
+    }
+}
+
+bitflags::bitflags! {
+    pub struct CreditTracking: u8 {
+        const REWARD_A = 1 << 0;
+        const REWARD_B = 1 << 1;
+    }
+}
+
 // --------------------------------------- farm reward
 
 #[proc_macros::assert_size(4)]
@@ -406,8 +546,22 @@ pub struct FarmReward {
 
     pub times: TimeTracker,
 
+    /// actually paid out of the pot to farmers, same as the FundsTracker fields only ever goes up
+    /// (!) only tracked since this field was added - on farms created before that it starts at 0,
+    /// so accrued_unclaimed is overstated by whatever had been claimed until then
+    pub total_claimed: u64,
+
+    /// same as total_accrued_to_stakers, but summed up at farmer level
+    /// farm-level accrual is rounded up and farmer-level down, the difference is dust
+    /// (!) same as above only tracked since added - sunset refuses to trust it unless the farm's
+    /// credit_tracking says it's been tracked since before anything accrued
+    pub total_credited_to_farmers: u64,
+
+    /// rounding dust (and anything sent to the pot directly) swept to the treasury at sunset
+    pub total_dust_swept: u64,
+
     /// reserved for future updates, has to be /8
-    _reserved: [u8; 32],
+    _reserved: [u8; 8],
 }
 
 impl FarmReward {
@@ -421,6 +575,26 @@ impl FarmReward {
         Ok(())
     }
 
+    /// (!) THIS OPERATION IS IRREVERSIBLE
+    /// same as above, but only up to a checkpoint - the part of the reward past it can still be cancelled
+    /// an existing lock can only ever be extended, up to reward_end_ts
+    /// (!) variable only - fixed rewards can't be partially cancelled (cancel_unlocked_portion)
+    /// so a partial lock would quietly turn into a full one, those have to use lock_reward
+    fn lock_reward_until(&mut self, lock_end_ts: u64) -> ProgramResult {
+        if self.reward_type == RewardType::Fixed {
+            return Err(ErrorCode::InvalidParameter.into());
+        }
+
+        if lock_end_ts < self.times.lock_end_ts || lock_end_ts > self.times.reward_end_ts {
+            return Err(ErrorCode::InvalidLockEnd.into());
+        }
+
+        self.times.lock_end_ts = lock_end_ts;
+
+        // msg!("locked reward up to {}", lock_end_ts);
+        Ok(())
+    }
+
     fn is_locked(&self, now_ts: u64) -> bool {
         now_ts < self.times.lock_end_ts
     }
@@ -453,7 +627,7 @@ impl FarmReward {
 
     fn cancel_reward_by_type(&mut self, now_ts: u64) -> Result<u64, ProgramError> {
         if self.is_locked(now_ts) {
-            return Err(ErrorCode::RewardLocked.into());
+            return self.cancel_unlocked_portion();
         }
 
         match self.reward_type {
@@ -468,14 +642,117 @@ impl FarmReward {
         }
     }
 
+    /// (!) assumes every farmer has already been credited everything they'll ever accrue
+    /// whatever the pot holds above what's still owed to farmers comes out - any pending funding
+    /// gets refunded same as with cancellation, the rounding dust on top goes to the treasury
+    /// after this, the pot holds exactly the unclaimed farmer rewards
+    /// returns (refund_amount, dust_amount)
+    fn sunset_reward(&mut self, now_ts: u64, pot_balance: u64) -> Result<(u64, u64), ProgramError> {
+        if now_ts < self.times.reward_end_ts {
+            return Err(ErrorCode::RewardNotEnded.into());
+        }
+
+        let owed_to_farmers = self.total_credited_to_farmers.try_sub(self.total_claimed)?;
+        let sweep_amount = pot_balance.try_sub(owed_to_farmers)?;
+
+        // close the books - nothing is pending or reserved anymore
+        let refund_amount = self.funds.pending_amount()?;
+        let dust_amount = sweep_amount.try_sub(refund_amount)?;
+
+        self.funds.total_refunded.try_add_assign(refund_amount)?;
+        self.total_dust_swept.try_add_assign(dust_amount)?;
+        self.fixed_rate.reserved_amount = 0;
+        self.variable_rate.reward_rate = Number128::ZERO;
+
+        Ok((refund_amount, dust_amount))
+    }
+
+    /// funding still needed to cover what's going to accrue to currently staked gems
+    /// - fixed: whatever is reserved for enrolled farmers, but not yet accrued to them
+    /// - variable: the rate over the rest of the reward, as long as anyone is staked
+    fn remaining_required_funding(
+        &self,
+        farm_rarity_points_staked: u64,
+    ) -> Result<u64, ProgramError> {
+        match self.reward_type {
+            RewardType::Variable => {
+                if farm_rarity_points_staked == 0 {
+                    return Ok(0);
+                }
+
+                let remaining_sec = self
+                    .times
+                    .reward_end_ts
+                    .try_sub(self.variable_rate.reward_last_updated_ts)?;
+
+                Number128::from(remaining_sec)
+                    .try_mul(self.variable_rate.reward_rate)?
+                    .as_u64_ceil(0) //overestimate the liability
+            }
+            RewardType::Fixed => Ok(self.fixed_rate.reserved_amount),
+        }
+    }
+
+    /// accrued to farmers, but not yet claimed by them
+    pub fn accrued_unclaimed(&self) -> Result<u64, ProgramError> {
+        self.funds
+            .total_accrued_to_stakers
+            .try_sub(self.total_claimed)
+    }
+
+    /// what the pot should be holding
+    pub fn held_amount(&self) -> Result<u64, ProgramError> {
+        self.funds
+            .total_funded
+            .try_sub(self.funds.total_refunded)?
+            .try_sub(self.total_claimed)?
+            .try_sub(self.total_dust_swept)
+    }
+
+    /// pending_amount / max(outstanding liability, 1), in bps
+    /// outstanding liability = accrued but unclaimed + funding still required for staked gems
+    /// anything below 10000 means the reward can't pay out everything it has promised
+    pub fn solvency_ratio_bps(&self, farm_rarity_points_staked: u64) -> Result<u64, ProgramError> {
+        let liability = self
+            .accrued_unclaimed()?
+            .try_add(self.remaining_required_funding(farm_rarity_points_staked)?)?;
+
+        let ratio = (self.funds.pending_amount()? as u128)
+            .try_mul(10000)?
+            .try_div(std::cmp::max(liability, 1) as u128)?;
+
+        ratio.try_cast()
+    }
+
+    /// if the reward is only locked until a checkpoint, whatever comes after it can be refunded
+    /// (!) variable only - fixed rewards promise each farmer a full schedule at enrollment,
+    /// so there's no clean cut-off point that wouldn't break existing promises
+    fn cancel_unlocked_portion(&mut self) -> Result<u64, ProgramError> {
+        if self.times.lock_end_ts >= self.times.reward_end_ts {
+            return Err(ErrorCode::RewardLocked.into());
+        }
+
+        match self.reward_type {
+            RewardType::Variable => self.variable_rate.cancel_reward_after(
+                self.times.lock_end_ts,
+                &mut self.times,
+                &mut self.funds,
+            ),
+            RewardType::Fixed => Err(ErrorCode::RewardLocked.into()),
+        }
+    }
+
     fn update_accrued_reward_by_type(
         &mut self,
         now_ts: u64,
         farm_rarity_points_staked: u64,
+        farmer_gems_staked: Option<u64>,
         farmer_rarity_points_staked: Option<u64>,
-        farmer_reward: Option<&mut FarmerReward>,
+        mut farmer_reward: Option<&mut FarmerReward>,
         reenroll: bool,
     ) -> ProgramResult {
+        let accrued_before = farmer_reward.as_ref().map(|r| r.accrued_reward);
+
         match self.reward_type {
             RewardType::Variable => self.variable_rate.update_accrued_reward(
                 now_ts,
@@ -483,8 +760,8 @@ impl FarmReward {
                 &mut self.funds,
                 farm_rarity_points_staked,
                 farmer_rarity_points_staked,
-                farmer_reward,
-            ),
+                farmer_reward.as_deref_mut(),
+            )?,
             RewardType::Fixed => {
                 // for fixed rewards we only update if Farmer has been passed
                 if farmer_reward.is_none() {
@@ -495,12 +772,21 @@ impl FarmReward {
                     now_ts,
                     &mut self.times,
                     &mut self.funds,
+                    farmer_gems_staked.unwrap(),
                     farmer_rarity_points_staked.unwrap(),
-                    farmer_reward.unwrap(),
+                    farmer_reward.as_deref_mut().unwrap(),
                     reenroll,
-                )
+                )?
             }
         }
+
+        // whatever the farmer got (rounded down) on top of what they had
+        if let (Some(before), Some(farmer_reward)) = (accrued_before, farmer_reward) {
+            self.total_credited_to_farmers
+                .try_add_assign(farmer_reward.accrued_reward.try_sub(before)?)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -508,6 +794,74 @@ impl FarmReward {
 mod tests {
     use super::*;
 
+    impl FarmReward {
+        pub fn new(reward_type: RewardType) -> Self {
+            Self {
+                reward_mint: Pubkey::default(),
+                reward_pot: Pubkey::default(),
+                reward_type,
+                fixed_rate: FixedRateReward::new(0),
+                variable_rate: VariableRateReward::new(0, 0),
+                funds: FundsTracker {
+                    total_funded: 0,
+                    total_refunded: 0,
+                    total_accrued_to_stakers: 0,
+                },
+                times: TimeTracker {
+                    duration_sec: 0,
+                    reward_end_ts: 0,
+                    lock_end_ts: 0,
+                },
+                total_claimed: 0,
+                total_credited_to_farmers: 0,
+                total_dust_swept: 0,
+                _reserved: [0; 8],
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_rewards_staged_catchup() {
+        // zeroed out accounts, same as right after init
+        let mut farm = Farm::deserialize(&mut &[0u8; 1000][..]).unwrap();
+        let mut farmer = Farmer::deserialize(&mut &[0u8; 1000][..]).unwrap();
+
+        // 10 tokens / s, the farmer being the only one staked
+        farm.reward_a = FarmReward::new(RewardType::Variable);
+        farm.reward_a.variable_rate = VariableRateReward::new(10, 0);
+        farm.reward_a.times = TimeTracker {
+            duration_sec: 1000,
+            reward_end_ts: 1000,
+            lock_end_ts: 0,
+        };
+        farm.reward_a.funds.total_funded = 10_000;
+        farm.rarity_points_staked = 1;
+        farmer.rarity_points_staked = 1;
+        farm.max_catchup_per_call = 300;
+
+        // idle for 100s, then refreshed repeatedly
+        farm.update_rewards(100, Some(&mut farmer), true).unwrap();
+        assert_eq!(300, farmer.reward_a.accrued_reward);
+        assert_eq!(700, farmer.reward_a.pending_catchup_reward);
+
+        // the farm side accounts for the full amount straight away
+        assert_eq!(1000, farm.reward_a.funds.total_accrued_to_stakers);
+
+        farm.update_rewards(100, Some(&mut farmer), true).unwrap();
+        assert_eq!(600, farmer.reward_a.accrued_reward);
+
+        farm.update_rewards(100, Some(&mut farmer), true).unwrap();
+        assert_eq!(900, farmer.reward_a.accrued_reward);
+
+        farm.update_rewards(100, Some(&mut farmer), true).unwrap();
+        assert_eq!(1000, farmer.reward_a.accrued_reward);
+        assert_eq!(0, farmer.reward_a.pending_catchup_reward);
+
+        // staged refreshes sum up to exactly what accrued over the idle period
+        assert_eq!(10 * 100, farmer.reward_a.accrued_reward);
+        assert_eq!(0, farmer.reward_b.accrued_reward);
+    }
+
     #[test]
     fn test_time_tracker() {
         let times = TimeTracker {
@@ -545,12 +899,209 @@ mod tests {
 
     #[test]
     fn test_funds_tracker() {
-        let funds = FundsTracker {
+        let mut reward = FarmReward::new(RewardType::Variable);
+        reward.funds = FundsTracker {
             total_funded: 100,
             total_refunded: 50,
             total_accrued_to_stakers: 30,
         };
+        reward.total_claimed = 10;
+
+        assert_eq!(20, reward.funds.pending_amount().unwrap());
+        assert_eq!(20, reward.accrued_unclaimed().unwrap());
+    }
 
-        assert_eq!(20, funds.pending_amount().unwrap());
+    #[test]
+    fn test_solvency_ratio_solvent() {
+        let mut reward = FarmReward::new(RewardType::Variable);
+        reward.variable_rate = VariableRateReward::new(2, 150);
+        reward.times.reward_end_ts = 200;
+        reward.funds = FundsTracker {
+            total_funded: 400,
+            total_refunded: 0,
+            total_accrued_to_stakers: 100,
+        };
+        reward.total_claimed = 40;
+
+        // pending = 400 - 100 = 300, owed = 100 - 40 + 2 * 50 = 160
+        assert_eq!(18750, reward.solvency_ratio_bps(10).unwrap());
+
+        // nothing staked - nothing more will accrue, so only what's owed counts
+        assert_eq!(50000, reward.solvency_ratio_bps(0).unwrap());
+    }
+
+    #[test]
+    fn test_solvency_ratio_insolvent() {
+        let mut reward = FarmReward::new(RewardType::Fixed);
+        reward.fixed_rate = FixedRateReward::new(150);
+        reward.funds = FundsTracker {
+            total_funded: 300,
+            total_refunded: 100,
+            total_accrued_to_stakers: 80,
+        };
+        reward.total_claimed = 20;
+
+        // pending = 300 - 100 - 80 = 120, owed = 80 - 20 + 150 = 210
+        assert_eq!(5714, reward.solvency_ratio_bps(10).unwrap());
+
+        // nothing owed at all
+        reward.fixed_rate.reserved_amount = 0;
+        reward.total_claimed = 80;
+        assert_eq!(1200000, reward.solvency_ratio_bps(10).unwrap());
+    }
+
+    #[test]
+    fn test_sunset_reward_sweeps_dust() {
+        let mut reward = FarmReward::new(RewardType::Variable);
+        reward.times = TimeTracker {
+            duration_sec: 40,
+            reward_end_ts: 40,
+            lock_end_ts: 0,
+        };
+        reward.variable_rate = VariableRateReward::new(25, 0);
+        reward.funds.total_funded = 1100;
+
+        // 2 farmers (3 + 4 = 7 points), both refreshed every second - lots of rounding
+        let mut farmers = [(3, FarmerReward::new()), (4, FarmerReward::new())];
+        for (_, f) in farmers.iter_mut() {
+            f.accrued_reward = 0;
+            f.variable_rate
+                .last_recorded_accrued_reward_per_rarity_point = Number128::ZERO;
+        }
+
+        for now_ts in 1..=40 {
+            for (points, f) in farmers.iter_mut() {
+                reward
+                    .update_accrued_reward_by_type(
+                        now_ts,
+                        7,
+                        Some(*points),
+                        Some(*points),
+                        Some(f),
+                        true,
+                    )
+                    .unwrap();
+            }
+        }
+
+        // each second the farm accrues ceil(25/7 * 7) = 25, but farmers only get
+        // floor(25/7 * 3) + floor(25/7 * 4) = 10 + 14 = 24 - leaving 1 of dust behind
+        assert_eq!(1000, reward.funds.total_accrued_to_stakers);
+        assert_eq!(960, reward.total_credited_to_farmers);
+
+        // one farmer claims before sunset, the other after
+        let mut pot_balance = reward.funds.total_funded;
+        let claimed = farmers[0].1.claim_reward(pot_balance).unwrap();
+        reward.total_claimed += claimed;
+        pot_balance -= claimed;
+
+        // can't sunset while the reward is still running
+        assert!(reward.sunset_reward(39, pot_balance).is_err());
+
+        // the 100 that never accrued gets refunded, only the dust goes to the treasury
+        let (refunded, swept) = reward.sunset_reward(40, pot_balance).unwrap();
+        assert_eq!(100, refunded);
+        assert_eq!(40, swept);
+        pot_balance -= refunded + swept;
+        assert_eq!(0, reward.funds.pending_amount().unwrap());
+        assert_eq!(100, reward.funds.total_refunded);
+        assert_eq!(40, reward.total_dust_swept);
+        assert_eq!(reward.held_amount().unwrap(), pot_balance);
+        assert_eq!(farmers[1].1.outstanding_reward().unwrap(), pot_balance);
+
+        let claimed = farmers[1].1.claim_reward(pot_balance).unwrap();
+        pot_balance -= claimed;
+
+        // escrow fully drained
+        assert_eq!(0, pot_balance);
+        assert_eq!(
+            1100,
+            farmers[0].1.paid_out_reward + farmers[1].1.paid_out_reward + refunded + swept
+        );
+    }
+
+    #[test]
+    fn test_sunset_requires_credit_tracking() {
+        let mut farm = Farm::deserialize(&mut &[0u8; 1000][..]).unwrap();
+        farm.reward_a.times.reward_end_ts = 100;
+
+        // accrued before total_credited_to_farmers was around - it's still 0
+        farm.reward_a.funds.total_funded = 1000;
+        farm.reward_a.funds.total_accrued_to_stakers = 300;
+        farm.update_rewards(100, None, true).unwrap();
+
+        let mint = farm.reward_a.reward_mint;
+        assert!(farm.sunset_reward_by_mint(100, mint, 1000).is_err());
+
+        // tracked from the start
+        farm.reward_a.funds.total_accrued_to_stakers = 0;
+        farm.update_rewards(100, None, true).unwrap();
+        assert_eq!(
+            (1000, 0),
+            farm.sunset_reward_by_mint(100, mint, 1000).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_lock_reward_until_rejects_fixed() {
+        let mut reward = FarmReward::new(RewardType::Fixed);
+        reward.times = TimeTracker {
+            duration_sec: 100,
+            reward_end_ts: 100,
+            lock_end_ts: 0,
+        };
+
+        assert!(reward.lock_reward_until(60).is_err());
+        assert_eq!(0, reward.times.lock_end_ts);
+
+        // a full lock is still fine
+        reward.lock_reward().unwrap();
+        assert_eq!(100, reward.times.lock_end_ts);
+    }
+
+    #[test]
+    fn test_cancel_unlocked_portion() {
+        let mut reward = FarmReward::new(RewardType::Variable);
+        reward.variable_rate = VariableRateReward::new(10, 20);
+        reward.times = TimeTracker {
+            duration_sec: 100,
+            reward_end_ts: 100,
+            lock_end_ts: 0,
+        };
+        reward.funds.total_funded = 1000;
+        reward.funds.total_accrued_to_stakers = 200;
+
+        // can't lock past the end of the reward
+        assert!(reward.lock_reward_until(101).is_err());
+
+        // lock up to the midpoint
+        reward.lock_reward_until(60).unwrap();
+        assert_eq!(60, reward.times.lock_end_ts);
+
+        // once locked, can only extend
+        assert!(reward.lock_reward_until(50).is_err());
+
+        // the 40s past the lock get refunded
+        assert_eq!(400, reward.cancel_reward_by_type(20).unwrap());
+        assert_eq!(400, reward.funds.total_refunded);
+        assert_eq!(60, reward.times.reward_end_ts);
+        assert_eq!(60, reward.times.duration_sec);
+
+        // locked portion stays intact - exactly enough for 40s more at the original rate
+        assert_eq!(Number128::from(10u64), reward.variable_rate.reward_rate);
+        assert_eq!(400, reward.funds.pending_amount().unwrap());
+
+        // nothing past the lock anymore
+        assert!(reward.cancel_reward_by_type(30).is_err());
+
+        // fixed rewards can't be partially cancelled
+        let mut reward = FarmReward::new(RewardType::Fixed);
+        reward.times = TimeTracker {
+            duration_sec: 100,
+            reward_end_ts: 100,
+            lock_end_ts: 60,
+        };
+        reward.funds.total_funded = 1000;
+        assert!(reward.cancel_reward_by_type(20).is_err());
     }
 }
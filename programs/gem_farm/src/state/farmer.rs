@@ -113,6 +113,43 @@ impl Farmer {
         Ok(())
     }
 
+    /// takes gems out of the stake right away (so they stop accruing), but only lets them
+    /// out of the vault once unlock_ts is reached
+    /// (!) can't take out the whole stake - that's what a regular unstake is for, and a Staked
+    /// farmer with nothing staked would keep the farm from ever being sunset
+    pub fn schedule_unstake(
+        &mut self,
+        now_ts: u64,
+        cooldown_period_sec: u64,
+        tranche: UnstakeTranche,
+        schedule: &mut UnstakeSchedule,
+    ) -> ProgramResult {
+        if self.state != FarmerState::Staked
+            || tranche.gems == 0
+            || tranche.gems >= self.gems_staked
+            || tranche.rarity_points > self.rarity_points_staked
+        {
+            return Err(ErrorCode::InvalidParameter.into());
+        }
+
+        // same guarantees as a regular unstake - min staking, then a full cooldown
+        let earliest_unlock_ts = std::cmp::max(
+            self.min_staking_ends_ts,
+            now_ts.try_add(cooldown_period_sec)?,
+        );
+        if tranche.unlock_ts < earliest_unlock_ts {
+            return Err(ErrorCode::InvalidUnlockTs.into());
+        }
+
+        schedule.add_tranche(tranche)?;
+
+        self.gems_staked.try_sub_assign(tranche.gems)?;
+        self.rarity_points_staked
+            .try_sub_assign(tranche.rarity_points)?;
+
+        Ok(())
+    }
+
     fn can_end_staking(&self, now_ts: u64) -> bool {
         now_ts >= self.min_staking_ends_ts
     }
@@ -139,8 +176,11 @@ pub struct FarmerReward {
 
     pub fixed_rate: FarmerFixedRateReward,
 
+    /// accrued but held back by the farm's max_catchup_per_call, credited on future updates
+    pub pending_catchup_reward: u64,
+
     /// reserved for future updates, has to be /8
-    _reserved: [u8; 32],
+    _reserved: [u8; 24],
 }
 
 impl FarmerReward {
@@ -177,6 +217,25 @@ impl FarmerReward {
 
         Ok(())
     }
+
+    /// caps how much of the reward accrued since accrued_before gets credited in one update,
+    /// the rest is parked in pending_catchup_reward and released on subsequent updates
+    /// (!) max_catchup of 0 means no cap, which also releases anything still pending
+    pub fn apply_catchup_cap(&mut self, accrued_before: u64, max_catchup: u64) -> ProgramResult {
+        let newly_accrued = self.accrued_reward.try_sub(accrued_before)?;
+        let available = self.pending_catchup_reward.try_add(newly_accrued)?;
+
+        let to_credit = if max_catchup == 0 {
+            available
+        } else {
+            std::cmp::min(available, max_catchup)
+        };
+
+        self.accrued_reward = accrued_before.try_add(to_credit)?;
+        self.pending_catchup_reward = available.try_sub(to_credit)?;
+
+        Ok(())
+    }
 }
 
 // --------------------------------------- variable rate reward
@@ -320,7 +379,8 @@ mod tests {
                     _reserved: [0; 16],
                 },
                 fixed_rate: FarmerFixedRateReward::new(),
-                _reserved: [0; 32],
+                pending_catchup_reward: 0,
+                _reserved: [0; 24],
             }
         }
     }
@@ -382,4 +442,46 @@ mod tests {
         r.claim_reward(100).unwrap();
         assert_eq!(23, r.outstanding_reward().unwrap());
     }
+
+    #[test]
+    fn test_farmer_reward_catchup_cap() {
+        let mut r = FarmerReward::new();
+
+        // long idle - a single update accrues 1000 on top of the 123 already there
+        r.update_fixed_reward(9999, 1000).unwrap();
+        r.apply_catchup_cap(123, 300).unwrap();
+        assert_eq!(423, r.accrued_reward);
+        assert_eq!(700, r.pending_catchup_reward);
+
+        // subsequent refreshes (nothing new accrued) release the remainder in stages
+        r.apply_catchup_cap(423, 300).unwrap();
+        assert_eq!(723, r.accrued_reward);
+        assert_eq!(400, r.pending_catchup_reward);
+
+        r.apply_catchup_cap(723, 300).unwrap();
+        assert_eq!(1023, r.accrued_reward);
+        assert_eq!(100, r.pending_catchup_reward);
+
+        // fresh accrual is queued behind what's pending
+        r.update_fixed_reward(9999, 50).unwrap();
+        r.apply_catchup_cap(1023, 300).unwrap();
+        assert_eq!(1173, r.accrued_reward);
+        assert_eq!(0, r.pending_catchup_reward);
+
+        // staged updates sum up to the full amount accrued
+        assert_eq!(123 + 1000 + 50, r.accrued_reward);
+    }
+
+    #[test]
+    fn test_farmer_reward_catchup_cap_disabled() {
+        let mut r = FarmerReward::new();
+        r.pending_catchup_reward = 500;
+
+        // no cap - everything accrued and pending is credited at once
+        r.update_variable_reward(10, Number128::from(50u64))
+            .unwrap();
+        r.apply_catchup_cap(123, 0).unwrap();
+        assert_eq!(633, r.accrued_reward);
+        assert_eq!(0, r.pending_catchup_reward);
+    }
 }
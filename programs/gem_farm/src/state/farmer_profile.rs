@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use gem_common::errors::ErrorCode;
+
+/// optional details about the farmer, kept out of the Farmer account so that its layout
+/// (and with it every existing farmer) stays untouched
+#[proc_macros::assert_size(128)]
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct FarmerProfile {
+    pub farmer: Pubkey,
+
+    /// an account the farmer designates to act on their behalf off-chain (eg a bot)
+    /// not checked by the program itself. Pubkey::default() if not set
+    pub delegate: Pubkey,
+
+    /// records who referred the farmer to the farm, can only be set once
+    /// Pubkey::default() if not set
+    pub referrer: Pubkey,
+
+    /// reserved for future updates, has to be /8
+    _reserved: [u8; 32],
+}
+
+impl FarmerProfile {
+    pub fn set_referrer(&mut self, referrer: Pubkey, identity: Pubkey) -> ProgramResult {
+        if self.referrer != Pubkey::default() {
+            return Err(ErrorCode::ReferrerAlreadySet.into());
+        }
+
+        // can't refer yourself
+        if referrer == identity {
+            return Err(ErrorCode::InvalidParameter.into());
+        }
+
+        self.referrer = referrer;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_referrer() {
+        let mut profile = FarmerProfile::deserialize(&mut &[0u8; 128][..]).unwrap();
+        let identity = Pubkey::new_unique();
+        let referrer = Pubkey::new_unique();
+
+        assert!(profile.set_referrer(identity, identity).is_err());
+        assert_eq!(Pubkey::default(), profile.referrer);
+
+        profile.set_referrer(referrer, identity).unwrap();
+        assert_eq!(referrer, profile.referrer);
+
+        // set once
+        assert!(profile
+            .set_referrer(Pubkey::new_unique(), identity)
+            .is_err());
+        assert_eq!(referrer, profile.referrer);
+    }
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use gem_common::{errors::ErrorCode, *};
 
-use crate::state::*;
+use crate::{number128::Number128, state::*};
 
 #[proc_macros::assert_size(4)]
 #[repr(C)]
@@ -240,9 +240,14 @@ impl FixedRateSchedule {
         //   as well as farm.reward_x.funds and farmer.paid_out_reward / farmer.accrued_reward
         //   then we'd do payouts in u64 and subtract the amount from u128 stored (eg 123.123 - 123.0)
         // maybe in v1++, if there's demand from users
-        rarity_points
-            .try_mul(per_rarity_point)?
-            .try_div(self.denominator)
+        // (going through Number128 here is exact, as long as no multipliers kick in)
+        effective_accrual(
+            Number128::from(per_rarity_point),
+            rarity_points,
+            &AccrualMultipliers::default(),
+        )?
+        .try_div_int(self.denominator)?
+        .as_u64(0)
     }
 }
 
@@ -307,10 +312,18 @@ impl FixedRateReward {
         now_ts: u64,
         times: &mut TimeTracker,
         funds: &mut FundsTracker,
+        farmer_gems_staked: u64,
         farmer_rarity_points_staked: u64,
         farmer_reward: &mut FarmerReward,
         reenroll: bool,
     ) -> ProgramResult {
+        // a farmer with no gems staked can't accrue anything - but they might still have a stale
+        // begin_staking_ts lying around, which would trip the graduation logic below
+        if farmer_gems_staked == 0 {
+            // msg!("farmer has no gems staked, means no new rewards accrue");
+            return Ok(());
+        }
+
         let newly_accrued_reward = farmer_reward
             .fixed_rate
             .newly_accrued_reward(now_ts, farmer_rarity_points_staked)?;
@@ -602,6 +615,72 @@ mod tests {
         base.verify_schedule_invariants();
     }
 
+    impl FixedRateReward {
+        pub fn new(reserved_amount: u64) -> Self {
+            Self {
+                schedule: FixedRateSchedule::new_base(3, 1),
+                reserved_amount,
+                _reserved: [0; 32],
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_accrued_reward_zero_gems_staked() {
+        let mut reward = FixedRateReward {
+            schedule: FixedRateSchedule::new_base(3, 1),
+            reserved_amount: 500,
+            _reserved: [0; 32],
+        };
+        let mut times = TimeTracker {
+            duration_sec: 100,
+            reward_end_ts: 300,
+            lock_end_ts: 0,
+        };
+        let mut funds = FundsTracker {
+            total_funded: 1000,
+            total_refunded: 0,
+            total_accrued_to_stakers: 200,
+        };
+
+        // stale tracker: begin_staking_ts is still set, and the schedule is due for graduation
+        let mut farmer_reward = FarmerReward::new();
+        farmer_reward.fixed_rate.begin_staking_ts = 100;
+        farmer_reward.fixed_rate.begin_schedule_ts = 150;
+        assert!(farmer_reward.fixed_rate.is_staked());
+        assert!(farmer_reward.fixed_rate.is_time_to_graduate(250).unwrap());
+        let original = farmer_reward.fixed_rate;
+
+        reward
+            .update_accrued_reward(250, &mut times, &mut funds, 0, 0, &mut farmer_reward, true)
+            .unwrap();
+
+        // farm untouched
+        assert_eq!(reward.reserved_amount, 500);
+        assert_eq!(funds.total_accrued_to_stakers, 200);
+        assert_eq!(times.duration_sec, 100);
+        assert_eq!(times.reward_end_ts, 300);
+
+        // farmer untouched
+        assert_eq!(farmer_reward.accrued_reward, 123);
+        assert_eq!(
+            farmer_reward.fixed_rate.begin_staking_ts,
+            original.begin_staking_ts
+        );
+        assert_eq!(
+            farmer_reward.fixed_rate.begin_schedule_ts,
+            original.begin_schedule_ts
+        );
+        assert_eq!(
+            farmer_reward.fixed_rate.last_updated_ts,
+            original.last_updated_ts
+        );
+        assert_eq!(
+            farmer_reward.fixed_rate.promised_duration,
+            original.promised_duration
+        );
+    }
+
     #[test]
     fn test_base_reward_amounts() {
         let base = FixedRateSchedule::new_base(3, 1);
@@ -621,6 +700,14 @@ mod tests {
         assert_eq!(amount, 0);
     }
 
+    #[test]
+    fn test_base_reward_amount_overflow() {
+        let base = FixedRateSchedule::new_base(u64::MAX / 2, 1);
+
+        // fits per rarity point, but not once multiplied out - errors instead of panicking
+        assert!(base.reward_amount(0, 1, 4).is_err());
+    }
+
     #[test]
     fn test_base_reward_amounts_with_denominator() {
         let base = FixedRateSchedule::new_base(3, 10);
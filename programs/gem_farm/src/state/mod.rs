@@ -1,11 +1,19 @@
+pub mod accrual;
+pub mod apr;
 pub mod authorization_proof;
 pub mod farm;
 pub mod farmer;
+pub mod farmer_profile;
 pub mod fixed_rewards;
+pub mod unstake_schedule;
 pub mod variable_rewards;
 
+pub use accrual::*;
+pub use apr::*;
 pub use authorization_proof::*;
 pub use farm::*;
 pub use farmer::*;
+pub use farmer_profile::*;
 pub use fixed_rewards::*;
+pub use unstake_schedule::*;
 pub use variable_rewards::*;
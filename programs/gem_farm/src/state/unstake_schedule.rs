@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+use gem_common::{errors::ErrorCode, *};
+
+use crate::state::*;
+
+pub const MAX_UNSTAKE_TRANCHES: usize = 4;
+
+#[proc_macros::assert_size(56)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct UnstakeTranche {
+    pub gem_mint: Pubkey,
+
+    /// 0 = slot is free
+    pub gems: u64,
+
+    /// fixed at the time of scheduling, this is what was taken out of the stake
+    pub rarity_points: u64,
+
+    pub unlock_ts: u64,
+}
+
+/// gems taken out of the farmer's stake, each waiting to become withdrawable at its unlock ts
+/// kept out of the Farmer account so that its layout (and with it every existing farmer) stays
+/// untouched. Created the first time the farmer schedules an unstake
+#[proc_macros::assert_size(288)]
+#[repr(C)]
+#[account]
+#[derive(Debug, Default)]
+pub struct UnstakeSchedule {
+    pub farmer: Pubkey,
+
+    pub tranches: [UnstakeTranche; MAX_UNSTAKE_TRANCHES],
+
+    /// reserved for future updates, has to be /8
+    _reserved: [u8; 32],
+}
+
+impl UnstakeSchedule {
+    /// tranches only hold for as long as the farmer keeps staking - a full unstake sends every gem
+    /// in the vault through cooldown, tranches included, and the schedule can't be cleared then
+    /// while current, whatever's scheduled is exactly what sits in the vault on top of the stake,
+    /// so a schedule left over from an earlier staking session no longer adds up
+    pub fn is_current(&self, farmer: &Farmer, gems_in_vault: u64) -> Result<bool, ProgramError> {
+        if farmer.state != FarmerState::Staked {
+            return Ok(false);
+        }
+
+        Ok(gems_in_vault.checked_sub(farmer.gems_staked) == Some(self.scheduled_gems(None)?))
+    }
+
+    pub fn reset(&mut self) {
+        self.tranches = [UnstakeTranche::default(); MAX_UNSTAKE_TRANCHES];
+    }
+
+    /// returns the index the tranche ended up at
+    pub fn add_tranche(&mut self, tranche: UnstakeTranche) -> Result<usize, ProgramError> {
+        let index = self
+            .tranches
+            .iter()
+            .position(|t| t.gems == 0)
+            .ok_or::<ProgramError>(ErrorCode::UnstakeScheduleFull.into())?;
+
+        self.tranches[index] = tranche;
+        Ok(index)
+    }
+
+    /// frees up the slot, returning what was in it
+    pub fn take_unlocked_tranche(
+        &mut self,
+        index: usize,
+        now_ts: u64,
+    ) -> Result<UnstakeTranche, ProgramError> {
+        let tranche = *self
+            .tranches
+            .get(index)
+            .filter(|t| t.gems > 0)
+            .ok_or::<ProgramError>(ErrorCode::InvalidParameter.into())?;
+
+        if now_ts < tranche.unlock_ts {
+            return Err(ErrorCode::TrancheLocked.into());
+        }
+
+        self.tranches[index] = UnstakeTranche::default();
+        Ok(tranche)
+    }
+
+    pub fn scheduled_gems(&self, gem_mint: Option<Pubkey>) -> Result<u64, ProgramError> {
+        let mut gems: u64 = 0;
+        for t in self.tranches.iter() {
+            if gem_mint.map_or(true, |m| m == t.gem_mint) {
+                gems.try_add_assign(t.gems)?;
+            }
+        }
+        Ok(gems)
+    }
+
+    pub fn scheduled_rarity_points(&self) -> Result<u64, ProgramError> {
+        let mut rarity_points: u64 = 0;
+        for t in self.tranches.iter() {
+            rarity_points.try_add_assign(t.rarity_points)?;
+        }
+        Ok(rarity_points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tranche(gem_mint: Pubkey, gems: u64, unlock_ts: u64) -> UnstakeTranche {
+        UnstakeTranche {
+            gem_mint,
+            gems,
+            rarity_points: gems * 2,
+            unlock_ts,
+        }
+    }
+
+    #[test]
+    fn test_unstake_schedule() {
+        let mint_a = Pubkey::new_unique();
+        let mint_b = Pubkey::new_unique();
+        let mut s = UnstakeSchedule::default();
+
+        assert_eq!(0, s.add_tranche(tranche(mint_a, 1, 100)).unwrap());
+        assert_eq!(1, s.add_tranche(tranche(mint_a, 2, 200)).unwrap());
+        assert_eq!(2, s.add_tranche(tranche(mint_b, 3, 300)).unwrap());
+
+        assert_eq!(3, s.scheduled_gems(Some(mint_a)).unwrap());
+        assert_eq!(6, s.scheduled_gems(None).unwrap());
+        assert_eq!(12, s.scheduled_rarity_points().unwrap());
+
+        // nothing comes out early
+        assert!(s.take_unlocked_tranche(1, 199).is_err());
+
+        // once unlocked, comes out exactly once and frees up the slot
+        assert_eq!(
+            tranche(mint_a, 2, 200),
+            s.take_unlocked_tranche(1, 200).unwrap()
+        );
+        assert!(s.take_unlocked_tranche(1, 200).is_err());
+        assert_eq!(1, s.scheduled_gems(Some(mint_a)).unwrap());
+
+        // freed slots get reused
+        assert_eq!(1, s.add_tranche(tranche(mint_b, 4, 400)).unwrap());
+        assert_eq!(3, s.add_tranche(tranche(mint_b, 5, 500)).unwrap());
+        assert!(s.add_tranche(tranche(mint_b, 6, 600)).is_err());
+
+        // out of bounds
+        assert!(s.take_unlocked_tranche(MAX_UNSTAKE_TRANCHES, 9999).is_err());
+    }
+
+    #[test]
+    fn test_unstake_schedule_is_current() {
+        let mut farmer = Farmer::deserialize(&mut &[0u8; 1000][..]).unwrap();
+        farmer.state = FarmerState::Staked;
+        farmer.gems_staked = 7;
+
+        let mut s = UnstakeSchedule::default();
+        s.add_tranche(tranche(Pubkey::new_unique(), 3, 100))
+            .unwrap();
+
+        // 10 in the vault = 7 staked + 3 scheduled
+        assert!(s.is_current(&farmer, 10).unwrap());
+
+        // fully unstaked - the tranche cools down with the rest of the vault
+        farmer.state = FarmerState::PendingCooldown;
+        farmer.gems_staked = 0;
+        assert!(!s.is_current(&farmer, 10).unwrap());
+
+        // staked again, this time with the whole vault
+        farmer.state = FarmerState::Staked;
+        farmer.gems_staked = 10;
+        assert!(!s.is_current(&farmer, 10).unwrap());
+
+        s.reset();
+        assert!(s.is_current(&farmer, 10).unwrap());
+    }
+}
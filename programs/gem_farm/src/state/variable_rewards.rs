@@ -88,6 +88,31 @@ impl VariableRateReward {
         Ok(refund_amount)
     }
 
+    /// refunds only what would have accrued after cutoff_ts, the reward then ends at cutoff_ts
+    /// the rate stays the same, so anything up to cutoff_ts accrues as originally promised
+    pub fn cancel_reward_after(
+        &mut self,
+        cutoff_ts: u64,
+        times: &mut TimeTracker,
+        funds: &mut FundsTracker,
+    ) -> Result<u64, ProgramError> {
+        let cut_duration = times.reward_end_ts.try_sub(cutoff_ts)?;
+
+        let refund_amount = std::cmp::min(
+            Number128::from(cut_duration)
+                .try_mul(self.reward_rate)?
+                .as_u64(0)?, //underestimate the refund
+            funds.pending_amount()?,
+        );
+        funds.total_refunded.try_add_assign(refund_amount)?;
+
+        times.duration_sec.try_sub_assign(cut_duration)?;
+        times.reward_end_ts = cutoff_ts;
+
+        // msg!("prepared a partial refund of {}", refund_amount);
+        Ok(refund_amount)
+    }
+
     pub fn update_accrued_reward(
         &mut self,
         now_ts: u64,
@@ -115,17 +140,20 @@ impl VariableRateReward {
 
         // update farmer, if one was passed
         if let Some(farmer_reward) = farmer_reward {
-            let newly_accrued_to_farmer = Number128::from(farmer_rarity_points_staked.unwrap())
-                .try_mul(
-                    self.accrued_reward_per_rarity_point.try_sub(
-                        farmer_reward
-                            .variable_rate
-                            .last_recorded_accrued_reward_per_rarity_point,
-                    )?,
-                )?;
+            let newly_accrued_to_farmer = effective_accrual(
+                self.accrued_reward_per_rarity_point.try_sub(
+                    farmer_reward
+                        .variable_rate
+                        .last_recorded_accrued_reward_per_rarity_point,
+                )?,
+                farmer_rarity_points_staked.unwrap(),
+                &AccrualMultipliers::default(),
+            )?;
+
+            let newly_accrued_to_farmer = newly_accrued_to_farmer.as_u64(0)?; //underestimate at farmer level
 
             farmer_reward.update_variable_reward(
-                newly_accrued_to_farmer.as_u64(0)?, //underestimate at farmer level
+                newly_accrued_to_farmer,
                 self.accrued_reward_per_rarity_point,
             )?;
         }
@@ -158,6 +186,17 @@ impl VariableRateReward {
 mod tests {
     use super::*;
 
+    impl VariableRateReward {
+        pub fn new(reward_rate: u64, reward_last_updated_ts: u64) -> Self {
+            Self {
+                reward_rate: Number128::from(reward_rate),
+                reward_last_updated_ts,
+                accrued_reward_per_rarity_point: Number128::ZERO,
+                _reserved: [0; 32],
+            }
+        }
+    }
+
     #[test]
     fn test_accrued_reward_per_rarity_point() {
         let var_reward = VariableRateReward {
@@ -440,6 +440,99 @@ export class GemBankClient extends AccountUtils {
     };
   }
 
+  async depositGemsBatch(
+    bank: PublicKey,
+    vault: PublicKey,
+    vaultOwner: PublicKey | Keypair,
+    gems: {
+      gemAmount: BN;
+      gemMint: PublicKey;
+      gemSource: PublicKey;
+      mintProof?: PublicKey;
+      metadata?: PublicKey;
+      creatorProof?: PublicKey;
+    }[]
+  ) {
+    const [vaultAuth, vaultAuthBump] = await this.findVaultAuthorityPDA(vault);
+
+    // every gem comes w/ the same # of accounts, so need to know which proofs to expect
+    const bankAcc = await this.fetchBankAcc(bank);
+    const expectsMintProof =
+      bankAcc.whitelistedMints > 0 || bankAcc.whitelistedCreators > 0;
+    const expectsCreatorProof = bankAcc.whitelistedCreators > 0;
+    const placeholder = SystemProgram.programId;
+
+    const remainingAccounts = [];
+    const gemBoxes = [];
+    const GDRs = [];
+    for (const gem of gems) {
+      const [gemBox] = await this.findGemBoxPDA(vault, gem.gemMint);
+      const [GDR] = await this.findGdrPDA(vault, gem.gemMint);
+      const [gemRarity] = await this.findRarityPDA(bank, gem.gemMint);
+      gemBoxes.push(gemBox);
+      GDRs.push(GDR);
+
+      remainingAccounts.push(
+        { pubkey: gemBox, isWritable: true, isSigner: false },
+        { pubkey: GDR, isWritable: true, isSigner: false },
+        { pubkey: gem.gemSource, isWritable: true, isSigner: false },
+        { pubkey: gem.gemMint, isWritable: false, isSigner: false },
+        { pubkey: gemRarity, isWritable: false, isSigner: false }
+      );
+      if (expectsMintProof)
+        remainingAccounts.push({
+          pubkey: gem.mintProof ?? placeholder,
+          isWritable: false,
+          isSigner: false,
+        });
+      if (expectsCreatorProof)
+        remainingAccounts.push(
+          {
+            pubkey: gem.metadata ?? placeholder,
+            isWritable: false,
+            isSigner: false,
+          },
+          {
+            pubkey: gem.creatorProof ?? placeholder,
+            isWritable: false,
+            isSigner: false,
+          }
+        );
+    }
+
+    const signers = [];
+    if (isKp(vaultOwner)) signers.push(<Keypair>vaultOwner);
+
+    console.log(`depositing ${gems.length} gem types into vault ${vault}`);
+    const txSig = await this.bankProgram.rpc.depositGemsBatch(
+      vaultAuthBump,
+      gems.map((g) => g.gemAmount),
+      {
+        accounts: {
+          bank,
+          vault,
+          owner: isKp(vaultOwner)
+            ? (<Keypair>vaultOwner).publicKey
+            : vaultOwner,
+          authority: vaultAuth,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        },
+        remainingAccounts,
+        signers,
+      }
+    );
+
+    return {
+      vaultAuth,
+      vaultAuthBump,
+      gemBoxes,
+      GDRs,
+      txSig,
+    };
+  }
+
   async withdrawGem(
     bank: PublicKey,
     vault: PublicKey,
@@ -74,12 +74,17 @@ export class AccountUtils {
     return token.getAccountInfo(tokenAccount);
   }
 
-  async findATA(mint: PublicKey, owner: PublicKey): Promise<PublicKey> {
+  async findATA(
+    mint: PublicKey,
+    owner: PublicKey,
+    allowOwnerOffCurve = false //needed for PDA owners
+  ): Promise<PublicKey> {
     return Token.getAssociatedTokenAddress(
       ASSOCIATED_TOKEN_PROGRAM_ID,
       TOKEN_PROGRAM_ID,
       mint,
-      owner
+      owner,
+      allowOwnerOffCurve
     );
   }
 }
@@ -14,6 +14,10 @@ export const feeAccount = new PublicKey(
   '2xhBxVVuXkdq2MRKerE9mr2s1szfHSedy21MVqf8gPoM'
 );
 
+export enum FarmFlags {
+  EscrowFrozen = 1 << 0,
+}
+
 //acts as an enum
 export const RewardType = {
   Variable: { variable: {} },
@@ -95,6 +99,14 @@ export class GemFarmClient extends GemBankClient {
     return this.farmProgram.account.farmer.fetch(farmer);
   }
 
+  async fetchFarmerProfileAcc(farmerProfile: PublicKey) {
+    return this.farmProgram.account.farmerProfile.fetch(farmerProfile);
+  }
+
+  async fetchUnstakeScheduleAcc(unstakeSchedule: PublicKey) {
+    return this.farmProgram.account.unstakeSchedule.fetch(unstakeSchedule);
+  }
+
   async fetchAuthorizationProofAcc(authorizationProof: PublicKey) {
     return this.farmProgram.account.authorizationProof.fetch(
       authorizationProof
@@ -120,6 +132,20 @@ export class GemFarmClient extends GemBankClient {
     ]);
   }
 
+  async findFarmerProfilePDA(farmer: PublicKey) {
+    return this.findProgramAddress(this.farmProgram.programId, [
+      'farmer_profile',
+      farmer,
+    ]);
+  }
+
+  async findUnstakeSchedulePDA(farmer: PublicKey) {
+    return this.findProgramAddress(this.farmProgram.programId, [
+      'unstake_schedule',
+      farmer,
+    ]);
+  }
+
   async findFarmAuthorityPDA(farm: PublicKey) {
     return this.findProgramAddress(this.farmProgram.programId, [farm]);
   }
@@ -312,6 +338,28 @@ export class GemFarmClient extends GemBankClient {
     return { txSig };
   }
 
+  async setMaxCatchup(
+    farm: PublicKey,
+    farmManager: PublicKey | Keypair,
+    maxCatchupPerCall: BN
+  ) {
+    const signers = [];
+    if (isKp(farmManager)) signers.push(<Keypair>farmManager);
+
+    console.log('setting max catch-up to', maxCatchupPerCall.toString());
+    const txSig = await this.farmProgram.rpc.setMaxCatchup(maxCatchupPerCall, {
+      accounts: {
+        farm,
+        farmManager: isKp(farmManager)
+          ? (<Keypair>farmManager).publicKey
+          : farmManager,
+      },
+      signers,
+    });
+
+    return { txSig };
+  }
+
   async payoutFromTreasury(
     farm: PublicKey,
     farmManager: PublicKey | Keypair,
@@ -355,6 +403,55 @@ export class GemFarmClient extends GemBankClient {
     };
   }
 
+  async payoutTokensFromTreasury(
+    farm: PublicKey,
+    farmManager: PublicKey | Keypair,
+    mint: PublicKey,
+    receiver: PublicKey,
+    amount: BN
+  ) {
+    const [farmTreasury, farmTreasuryBump] = await this.findFarmTreasuryPDA(
+      farm
+    );
+    const treasuryTokenAccount = await this.findATA(mint, farmTreasury, true);
+    const destination = await this.findATA(mint, receiver);
+
+    const signers = [];
+    if (isKp(farmManager)) signers.push(<Keypair>farmManager);
+
+    console.log('paying out tokens from treasury', farmTreasury.toBase58());
+    const txSig = await this.farmProgram.rpc.payoutTokensFromTreasury(
+      farmTreasuryBump,
+      amount,
+      {
+        accounts: {
+          farm,
+          farmManager: isKp(farmManager)
+            ? (<Keypair>farmManager).publicKey
+            : farmManager,
+          farmTreasury,
+          treasuryTokenAccount,
+          destination,
+          mint,
+          receiver,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        },
+        signers,
+      }
+    );
+
+    return {
+      farmTreasury,
+      farmTreasuryBump,
+      treasuryTokenAccount,
+      destination,
+      txSig,
+    };
+  }
+
   async addToBankWhitelist(
     farm: PublicKey,
     farmManager: PublicKey | Keypair,
@@ -449,7 +546,9 @@ export class GemFarmClient extends GemBankClient {
   async initFarmer(
     farm: PublicKey,
     farmerIdentity: PublicKey | Keypair,
-    payer: PublicKey | Keypair
+    payer: PublicKey | Keypair,
+    delegate: PublicKey | null = null,
+    referrer: PublicKey | null = null
   ) {
     const identityPk = isKp(farmerIdentity)
       ? (<Keypair>farmerIdentity).publicKey
@@ -463,26 +562,36 @@ export class GemFarmClient extends GemBankClient {
       identityPk
     );
     const [vaultAuth, vaultAuthBump] = await this.findVaultAuthorityPDA(vault); //nice-to-have
+    const [farmerProfile, farmerProfileBump] =
+      await this.findFarmerProfilePDA(farmer);
 
     const signers = [];
     if (isKp(farmerIdentity)) signers.push(<Keypair>farmerIdentity);
     if (isKp(payer)) signers.push(<Keypair>payer);
 
     console.log('adding farmer', identityPk.toBase58());
-    const txSig = await this.farmProgram.rpc.initFarmer(farmerBump, vaultBump, {
-      accounts: {
-        farm,
-        farmer,
-        identity: identityPk,
-        payer: isKp(payer) ? (<Keypair>payer).publicKey : payer,
-        feeAcc: feeAccount,
-        bank: farmAcc.bank,
-        vault,
-        gemBank: this.bankProgram.programId,
-        systemProgram: SystemProgram.programId,
-      },
-      signers,
-    });
+    const txSig = await this.farmProgram.rpc.initFarmer(
+      farmerBump,
+      vaultBump,
+      farmerProfileBump,
+      delegate,
+      referrer,
+      {
+        accounts: {
+          farm,
+          farmer,
+          identity: identityPk,
+          farmerProfile,
+          payer: isKp(payer) ? (<Keypair>payer).publicKey : payer,
+          feeAcc: feeAccount,
+          bank: farmAcc.bank,
+          vault,
+          gemBank: this.bankProgram.programId,
+          systemProgram: SystemProgram.programId,
+        },
+        signers,
+      }
+    );
 
     return {
       farmer,
@@ -491,10 +600,50 @@ export class GemFarmClient extends GemBankClient {
       vaultBump,
       vaultAuth,
       vaultAuthBump,
+      farmerProfile,
+      farmerProfileBump,
       txSig,
     };
   }
 
+  async updateFarmer(
+    farm: PublicKey,
+    farmerIdentity: PublicKey | Keypair,
+    delegate: PublicKey | null = null,
+    referrer: PublicKey | null = null
+  ) {
+    const identityPk = isKp(farmerIdentity)
+      ? (<Keypair>farmerIdentity).publicKey
+      : <PublicKey>farmerIdentity;
+
+    const [farmer, farmerBump] = await this.findFarmerPDA(farm, identityPk);
+    const [farmerProfile, farmerProfileBump] =
+      await this.findFarmerProfilePDA(farmer);
+
+    const signers = [];
+    if (isKp(farmerIdentity)) signers.push(<Keypair>farmerIdentity);
+
+    console.log('updating farmer', identityPk.toBase58());
+    const txSig = await this.farmProgram.rpc.updateFarmer(
+      farmerBump,
+      farmerProfileBump,
+      delegate,
+      referrer,
+      {
+        accounts: {
+          farm,
+          farmer,
+          identity: identityPk,
+          farmerProfile,
+          systemProgram: SystemProgram.programId,
+        },
+        signers,
+      }
+    );
+
+    return { farmer, farmerBump, farmerProfile, farmerProfileBump, txSig };
+  }
+
   async stakeCommon(
     farm: PublicKey,
     farmerIdentity: PublicKey | Keypair,
@@ -744,6 +893,164 @@ export class GemFarmClient extends GemBankClient {
     };
   }
 
+  async scheduleUnstake(
+    farm: PublicKey,
+    farmerIdentity: PublicKey | Keypair,
+    gemMint: PublicKey,
+    gems: BN,
+    unlockTs: BN
+  ) {
+    const identityPk = isKp(farmerIdentity)
+      ? (<Keypair>farmerIdentity).publicKey
+      : <PublicKey>farmerIdentity;
+
+    const farmAcc = await this.fetchFarmAcc(farm);
+
+    const [farmTreasury, farmTreasuryBump] = await this.findFarmTreasuryPDA(
+      farm
+    );
+    const [farmer, farmerBump] = await this.findFarmerPDA(farm, identityPk);
+    const [unstakeSchedule, unstakeScheduleBump] =
+      await this.findUnstakeSchedulePDA(farmer);
+    const [vault, vaultBump] = await this.findVaultPDA(
+      farmAcc.bank,
+      identityPk
+    );
+    const [GDR, GDRBump] = await this.findGdrPDA(vault, gemMint);
+    const [gemRarity, gemRarityBump] = await this.findRarityPDA(
+      farmAcc.bank,
+      gemMint
+    );
+
+    const signers = [];
+    if (isKp(farmerIdentity)) signers.push(<Keypair>farmerIdentity);
+
+    const txSig = await this.farmProgram.rpc.scheduleUnstake(
+      farmTreasuryBump,
+      farmerBump,
+      unstakeScheduleBump,
+      gemRarityBump,
+      gems,
+      unlockTs,
+      {
+        accounts: {
+          farm,
+          farmTreasury,
+          farmer,
+          identity: identityPk,
+          unstakeSchedule,
+          bank: farmAcc.bank,
+          vault,
+          gemDepositReceipt: GDR,
+          gemRarity,
+          systemProgram: SystemProgram.programId,
+        },
+        signers,
+      }
+    );
+
+    return {
+      farmer,
+      farmerBump,
+      unstakeSchedule,
+      unstakeScheduleBump,
+      vault,
+      vaultBump,
+      GDR,
+      GDRBump,
+      txSig,
+    };
+  }
+
+  async withdrawUnlockedTranche(
+    farm: PublicKey,
+    farmerIdentity: PublicKey | Keypair,
+    trancheIndex: number
+  ) {
+    const identityPk = isKp(farmerIdentity)
+      ? (<Keypair>farmerIdentity).publicKey
+      : <PublicKey>farmerIdentity;
+
+    const farmAcc = await this.fetchFarmAcc(farm);
+
+    const [farmer, farmerBump] = await this.findFarmerPDA(farm, identityPk);
+    const [unstakeSchedule, unstakeScheduleBump] =
+      await this.findUnstakeSchedulePDA(farmer);
+    const scheduleAcc = await this.fetchUnstakeScheduleAcc(unstakeSchedule);
+    const gemMint: PublicKey = scheduleAcc.tranches[trancheIndex].gemMint;
+
+    const [vault, vaultBump] = await this.findVaultPDA(
+      farmAcc.bank,
+      identityPk
+    );
+    const [farmAuth, farmAuthBump] = await this.findFarmAuthorityPDA(farm);
+
+    const [gemBox, gemBoxBump] = await this.findGemBoxPDA(vault, gemMint);
+    const [GDR, GDRBump] = await this.findGdrPDA(vault, gemMint);
+    const [vaultAuth, vaultAuthBump] = await this.findVaultAuthorityPDA(vault);
+    const [gemRarity, gemRarityBump] = await this.findRarityPDA(
+      farmAcc.bank,
+      gemMint
+    );
+    const gemDestination = await this.findATA(gemMint, identityPk);
+
+    const signers = [];
+    if (isKp(farmerIdentity)) signers.push(<Keypair>farmerIdentity);
+
+    const txSig = await this.farmProgram.rpc.withdrawUnlockedTranche(
+      farmAuthBump,
+      farmerBump,
+      unstakeScheduleBump,
+      vaultAuthBump,
+      gemBoxBump,
+      GDRBump,
+      gemRarityBump,
+      trancheIndex,
+      {
+        accounts: {
+          farm,
+          farmAuthority: farmAuth,
+          farmer,
+          identity: identityPk,
+          unstakeSchedule,
+          bank: farmAcc.bank,
+          vault,
+          vaultAuthority: vaultAuth,
+          gemBox,
+          gemDepositReceipt: GDR,
+          gemDestination,
+          gemMint,
+          gemRarity,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          gemBank: this.bankProgram.programId,
+        },
+        signers,
+      }
+    );
+
+    return {
+      farmer,
+      farmerBump,
+      unstakeSchedule,
+      unstakeScheduleBump,
+      vault,
+      vaultBump,
+      farmAuth,
+      farmAuthBump,
+      gemBox,
+      gemBoxBump,
+      GDR,
+      GDRBump,
+      vaultAuth,
+      vaultAuthBump,
+      gemDestination,
+      txSig,
+    };
+  }
+
   async refreshFarmer(
     farm: PublicKey,
     farmerIdentity: PublicKey | Keypair,
@@ -962,6 +1269,66 @@ export class GemFarmClient extends GemBankClient {
     };
   }
 
+  async sunsetReward(
+    farm: PublicKey,
+    farmManager: PublicKey | Keypair,
+    rewardMint: PublicKey,
+    receiver: PublicKey
+  ) {
+    const [farmAuth, farmAuthBump] = await this.findFarmAuthorityPDA(farm);
+    const [farmTreasury, farmTreasuryBump] = await this.findFarmTreasuryPDA(
+      farm
+    );
+    const [pot, potBump] = await this.findRewardsPotPDA(farm, rewardMint);
+    const rewardDestination = await this.findATA(rewardMint, receiver);
+    const treasuryDestination = await this.findATA(
+      rewardMint,
+      farmTreasury,
+      true
+    );
+
+    const signers = [];
+    if (isKp(farmManager)) signers.push(<Keypair>farmManager);
+
+    const txSig = await this.farmProgram.rpc.sunsetReward(
+      farmAuthBump,
+      farmTreasuryBump,
+      potBump,
+      {
+        accounts: {
+          farm,
+          farmManager: isKp(farmManager)
+            ? (<Keypair>farmManager).publicKey
+            : farmManager,
+          farmAuthority: farmAuth,
+          farmTreasury,
+          rewardPot: pot,
+          rewardDestination,
+          treasuryDestination,
+          rewardMint,
+          receiver,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        },
+        signers,
+      }
+    );
+
+    return {
+      farmAuth,
+      farmAuthBump,
+      farmTreasury,
+      farmTreasuryBump,
+      pot,
+      potBump,
+      rewardDestination,
+      treasuryDestination,
+      txSig,
+    };
+  }
+
   async lockReward(
     farm: PublicKey,
     farmManager: PublicKey | Keypair,
@@ -984,6 +1351,77 @@ export class GemFarmClient extends GemBankClient {
     return { txSig };
   }
 
+  async lockRewardUntil(
+    farm: PublicKey,
+    farmManager: PublicKey | Keypair,
+    rewardMint: PublicKey,
+    lockEndTs: BN
+  ) {
+    const signers = [];
+    if (isKp(farmManager)) signers.push(<Keypair>farmManager);
+
+    const txSig = await this.farmProgram.rpc.lockRewardUntil(lockEndTs, {
+      accounts: {
+        farm,
+        farmManager: isKp(farmManager)
+          ? (<Keypair>farmManager).publicKey
+          : farmManager,
+        rewardMint,
+      },
+      signers,
+    });
+
+    return { txSig };
+  }
+
+  async calcSolvencyRatio(farm: PublicKey) {
+    const txSig = await this.farmProgram.rpc.calcSolvencyRatio({
+      accounts: {
+        farm,
+      },
+    });
+
+    return { txSig };
+  }
+
+  async calcAprFunding(
+    farm: PublicKey,
+    targetAprBps: BN,
+    gemPrice: BN,
+    rewardPrice: BN,
+    gems: BN | null,
+    durationSec: BN
+  ) {
+    const txSig = await this.farmProgram.rpc.calcAprFunding(
+      targetAprBps,
+      gemPrice,
+      rewardPrice,
+      gems,
+      durationSec,
+      {
+        accounts: {
+          farm,
+        },
+      }
+    );
+
+    return { txSig };
+  }
+
+  async reportFrozenEscrow(farm: PublicKey) {
+    const farmAcc = await this.fetchFarmAcc(farm);
+
+    const txSig = await this.farmProgram.rpc.reportFrozenEscrow({
+      accounts: {
+        farm,
+        rewardAPot: farmAcc.rewardA.rewardPot,
+        rewardBPot: farmAcc.rewardB.rewardPot,
+      },
+    });
+
+    return { txSig };
+  }
+
   // --------------------------------------- rarity
 
   async addRaritiesToBank(
@@ -149,6 +149,27 @@ export type GemFarm = {
         }
       ]
     },
+    {
+      "name": "setMaxCatchup",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "maxCatchupPerCall",
+          "type": "u64"
+        }
+      ]
+    },
     {
       "name": "payoutFromTreasury",
       "accounts": [
@@ -198,6 +219,76 @@ export type GemFarm = {
         }
       ]
     },
+    {
+      "name": "payoutTokensFromTreasury",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
     {
       "name": "addToBankWhitelist",
       "accounts": [
@@ -325,6 +416,11 @@ export type GemFarm = {
           "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "farmerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "bank",
           "isMut": true,
@@ -364,6 +460,74 @@ export type GemFarm = {
         {
           "name": "bumpVault",
           "type": "u8"
+        },
+        {
+          "name": "bumpProfile",
+          "type": "u8"
+        },
+        {
+          "name": "delegate",
+          "type": {
+            "option": "publicKey"
+          }
+        },
+        {
+          "name": "referrer",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateFarmer",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "bumpProfile",
+          "type": "u8"
+        },
+        {
+          "name": "delegate",
+          "type": {
+            "option": "publicKey"
+          }
+        },
+        {
+          "name": "referrer",
+          "type": {
+            "option": "publicKey"
+          }
         }
       ]
     },
@@ -686,7 +850,7 @@ export type GemFarm = {
       ]
     },
     {
-      "name": "refreshFarmer",
+      "name": "scheduleUnstake",
       "accounts": [
         {
           "name": "farm",
@@ -694,28 +858,7 @@ export type GemFarm = {
           "isSigner": false
         },
         {
-          "name": "farmer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "identity",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bump",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "refreshFarmerSigned",
-      "accounts": [
-        {
-          "name": "farm",
+          "name": "farmTreasury",
           "isMut": true,
           "isSigner": false
         },
@@ -726,42 +869,32 @@ export type GemFarm = {
         },
         {
           "name": "identity",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
-        }
-      ],
-      "args": [
-        {
-          "name": "bump",
-          "type": "u8"
         },
         {
-          "name": "reenroll",
-          "type": "bool"
-        }
-      ]
-    },
-    {
-      "name": "authorizeFunder",
-      "accounts": [
-        {
-          "name": "farm",
+          "name": "unstakeSchedule",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "farmManager",
-          "isMut": true,
-          "isSigner": true
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "funderToAuthorize",
+          "name": "vault",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationProof",
-          "isMut": true,
+          "name": "gemDepositReceipt",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemRarity",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -772,77 +905,96 @@ export type GemFarm = {
       ],
       "args": [
         {
-          "name": "bump",
+          "name": "bumpTreasury",
           "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "deauthorizeFunder",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
         },
         {
-          "name": "farmManager",
-          "isMut": true,
-          "isSigner": true
+          "name": "bumpFarmer",
+          "type": "u8"
         },
         {
-          "name": "funderToDeauthorize",
-          "isMut": false,
-          "isSigner": false
+          "name": "bumpSchedule",
+          "type": "u8"
         },
         {
-          "name": "authorizationProof",
-          "isMut": true,
-          "isSigner": false
+          "name": "bumpRarity",
+          "type": "u8"
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "name": "gems",
+          "type": "u64"
+        },
         {
-          "name": "bump",
-          "type": "u8"
+          "name": "unlockTs",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "fundReward",
+      "name": "withdrawUnlockedTranche",
       "accounts": [
         {
           "name": "farm",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationProof",
+          "name": "farmAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizedFunder",
+          "name": "farmer",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "rewardPot",
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "unstakeSchedule",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rewardSource",
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rewardMint",
+          "name": "vaultAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemRarity",
           "isMut": false,
           "isSigner": false
         },
@@ -851,41 +1003,64 @@ export type GemFarm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bumpProof",
+          "name": "bumpAuth",
           "type": "u8"
         },
         {
-          "name": "bumpPot",
+          "name": "bumpFarmer",
           "type": "u8"
         },
         {
-          "name": "variableRateConfig",
-          "type": {
-            "option": {
-              "defined": "VariableRateConfig"
-            }
-          }
+          "name": "bumpSchedule",
+          "type": "u8"
         },
         {
-          "name": "fixedRateConfig",
-          "type": {
-            "option": {
-              "defined": "FixedRateConfig"
-            }
-          }
+          "name": "bumpVaultAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpGemBox",
+          "type": "u8"
+        },
+        {
+          "name": "bumpGdr",
+          "type": "u8"
+        },
+        {
+          "name": "bumpRarity",
+          "type": "u8"
+        },
+        {
+          "name": "index",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "cancelReward",
+      "name": "refreshFarmer",
       "accounts": [
         {
           "name": "farm",
@@ -893,69 +1068,91 @@ export type GemFarm = {
           "isSigner": false
         },
         {
-          "name": "farmManager",
+          "name": "farmer",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "farmAuthority",
+          "name": "identity",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "rewardPot",
+          "name": "bump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "refreshFarmerSigned",
+      "accounts": [
+        {
+          "name": "farm",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rewardDestination",
+          "name": "farmer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rewardMint",
+          "name": "identity",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
         },
         {
-          "name": "receiver",
+          "name": "reenroll",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "authorizeFunder",
+      "accounts": [
+        {
+          "name": "farm",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "funderToAuthorize",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "authorizationProof",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bumpAuth",
-          "type": "u8"
-        },
-        {
-          "name": "bumpPot",
+          "name": "bump",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "lockReward",
+      "name": "deauthorizeFunder",
       "accounts": [
         {
           "name": "farm",
@@ -968,38 +1165,63 @@ export type GemFarm = {
           "isSigner": true
         },
         {
-          "name": "rewardMint",
+          "name": "funderToDeauthorize",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "addRaritiesToBank",
+      "name": "fundReward",
       "accounts": [
         {
           "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationProof",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "farmManager",
+          "name": "authorizedFunder",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "farmAuthority",
-          "isMut": false,
+          "name": "rewardPot",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bank",
+          "name": "rewardSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "gemBank",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -1011,60 +1233,388 @@ export type GemFarm = {
       ],
       "args": [
         {
-          "name": "bumpAuth",
+          "name": "bumpProof",
           "type": "u8"
         },
         {
-          "name": "rarityConfigs",
+          "name": "bumpPot",
+          "type": "u8"
+        },
+        {
+          "name": "variableRateConfig",
           "type": {
-            "vec": {
-              "defined": "RarityConfig"
+            "option": {
+              "defined": "VariableRateConfig"
             }
           }
-        }
-      ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "authorizationProof",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "authorizedFunder",
-            "type": "publicKey"
-          },
-          {
-            "name": "farm",
-            "type": "publicKey"
-          },
-          {
-            "name": "reserved",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
+        },
+        {
+          "name": "fixedRateConfig",
+          "type": {
+            "option": {
+              "defined": "FixedRateConfig"
             }
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "farm",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "version",
-            "type": "u16"
-          },
-          {
-            "name": "farmManager",
-            "type": "publicKey"
-          },
-          {
+      "name": "cancelReward",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "sunsetReward",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "lockReward",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "lockRewardUntil",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lockEndTs",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "calcSolvencyRatio",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "calcAprFunding",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "targetAprBps",
+          "type": "u64"
+        },
+        {
+          "name": "gemPrice",
+          "type": "u64"
+        },
+        {
+          "name": "rewardPrice",
+          "type": "u64"
+        },
+        {
+          "name": "gems",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "durationSec",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "reportFrozenEscrow",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardAPot",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBPot",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addRaritiesToBank",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "rarityConfigs",
+          "type": {
+            "vec": {
+              "defined": "RarityConfig"
+            }
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "authorizationProof",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authorizedFunder",
+            "type": "publicKey"
+          },
+          {
+            "name": "farm",
+            "type": "publicKey"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "farm",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "farmManager",
+            "type": "publicKey"
+          },
+          {
             "name": "farmTreasury",
             "type": "publicKey"
           },
@@ -1127,12 +1677,24 @@ export type GemFarm = {
               "defined": "FarmReward"
             }
           },
+          {
+            "name": "maxCatchupPerCall",
+            "type": "u64"
+          },
+          {
+            "name": "flags",
+            "type": "u32"
+          },
+          {
+            "name": "creditTracking",
+            "type": "u8"
+          },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                64
+                51
               ]
             }
           }
@@ -1201,6 +1763,67 @@ export type GemFarm = {
           }
         ]
       }
+    },
+    {
+      "name": "farmerProfile",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "farmer",
+            "type": "publicKey"
+          },
+          {
+            "name": "delegate",
+            "type": "publicKey"
+          },
+          {
+            "name": "referrer",
+            "type": "publicKey"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "unstakeSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "farmer",
+            "type": "publicKey"
+          },
+          {
+            "name": "tranches",
+            "type": {
+              "array": [
+                {
+                  "defined": "UnstakeTranche"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
     }
   ],
   "types": [
@@ -1307,12 +1930,24 @@ export type GemFarm = {
               "defined": "TimeTracker"
             }
           },
+          {
+            "name": "totalClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "totalCreditedToFarmers",
+            "type": "u64"
+          },
+          {
+            "name": "totalDustSwept",
+            "type": "u64"
+          },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                32
+                8
               ]
             }
           }
@@ -1344,12 +1979,16 @@ export type GemFarm = {
               "defined": "FarmerFixedRateReward"
             }
           },
+          {
+            "name": "pendingCatchupReward",
+            "type": "u64"
+          },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                32
+                24
               ]
             }
           }
@@ -1418,6 +2057,30 @@ export type GemFarm = {
         ]
       }
     },
+    {
+      "name": "UnstakeTranche",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gemMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "gems",
+            "type": "u64"
+          },
+          {
+            "name": "rarityPoints",
+            "type": "u64"
+          },
+          {
+            "name": "unlockTs",
+            "type": "u64"
+          }
+        ]
+      }
+    },
     {
       "name": "TierConfig",
       "type": {
@@ -1659,12 +2322,180 @@ export const IDL: GemFarm = {
   "name": "gem_farm",
   "instructions": [
     {
-      "name": "initFarm",
+      "name": "initFarm",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardAPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardAMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPotA",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPotB",
+          "type": "u8"
+        },
+        {
+          "name": "rewardTypeA",
+          "type": {
+            "defined": "RewardType"
+          }
+        },
+        {
+          "name": "rewardTypeB",
+          "type": {
+            "defined": "RewardType"
+          }
+        },
+        {
+          "name": "farmConfig",
+          "type": {
+            "defined": "FarmConfig"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateFarm",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "config",
+          "type": {
+            "option": {
+              "defined": "FarmConfig"
+            }
+          }
+        },
+        {
+          "name": "manager",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setMaxCatchup",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "maxCatchupPerCall",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "payoutFromTreasury",
       "accounts": [
         {
           "name": "farm",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "farmManager",
@@ -1673,68 +2504,152 @@ export const IDL: GemFarm = {
         },
         {
           "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "lamports",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "payoutTokensFromTreasury",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
         {
           "name": "farmTreasury",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rewardAPot",
+          "name": "treasuryTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rewardAMint",
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rewardBPot",
+          "name": "receiver",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rewardBMint",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bank",
-          "isMut": true,
-          "isSigner": true
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "gemBank",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "addToBankWhitelist",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "feeAcc",
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "addressToWhitelist",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
+          "name": "whitelistProof",
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
@@ -1743,39 +2658,67 @@ export const IDL: GemFarm = {
           "type": "u8"
         },
         {
-          "name": "bumpTreasury",
+          "name": "bumpWl",
           "type": "u8"
         },
         {
-          "name": "bumpPotA",
+          "name": "whitelistType",
           "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "removeFromBankWhitelist",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "bumpPotB",
-          "type": "u8"
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "rewardTypeA",
-          "type": {
-            "defined": "RewardType"
-          }
+          "name": "farmAuthority",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "rewardTypeB",
-          "type": {
-            "defined": "RewardType"
-          }
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "farmConfig",
-          "type": {
-            "defined": "FarmConfig"
-          }
+          "name": "addressToRemove",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "whitelistProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpWl",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "updateFarm",
+      "name": "initFarmer",
       "accounts": [
         {
           "name": "farm",
@@ -1783,22 +2726,72 @@ export const IDL: GemFarm = {
           "isSigner": false
         },
         {
-          "name": "farmManager",
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "farmerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
           "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "config",
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVault",
+          "type": "u8"
+        },
+        {
+          "name": "bumpProfile",
+          "type": "u8"
+        },
+        {
+          "name": "delegate",
           "type": {
-            "option": {
-              "defined": "FarmConfig"
-            }
+            "option": "publicKey"
           }
         },
         {
-          "name": "manager",
+          "name": "referrer",
           "type": {
             "option": "publicKey"
           }
@@ -1806,30 +2799,25 @@ export const IDL: GemFarm = {
       ]
     },
     {
-      "name": "payoutFromTreasury",
+      "name": "updateFarmer",
       "accounts": [
         {
           "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmManager",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "farmAuthority",
+          "name": "farmer",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "farmTreasury",
+          "name": "identity",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "destination",
+          "name": "farmerProfile",
           "isMut": true,
           "isSigner": false
         },
@@ -1841,31 +2829,34 @@ export const IDL: GemFarm = {
       ],
       "args": [
         {
-          "name": "bumpAuth",
+          "name": "bump",
           "type": "u8"
         },
         {
-          "name": "bumpTreasury",
+          "name": "bumpProfile",
           "type": "u8"
         },
         {
-          "name": "lamports",
-          "type": "u64"
+          "name": "delegate",
+          "type": {
+            "option": "publicKey"
+          }
+        },
+        {
+          "name": "referrer",
+          "type": {
+            "option": "publicKey"
+          }
         }
       ]
     },
     {
-      "name": "addToBankWhitelist",
+      "name": "stake",
       "accounts": [
         {
           "name": "farm",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "farmManager",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "farmAuthority",
@@ -1873,23 +2864,23 @@ export const IDL: GemFarm = {
           "isSigner": false
         },
         {
-          "name": "bank",
+          "name": "farmer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "addressToWhitelist",
-          "isMut": false,
-          "isSigner": false
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "whitelistProof",
-          "isMut": true,
+          "name": "bank",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "vault",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1904,45 +2895,46 @@ export const IDL: GemFarm = {
           "type": "u8"
         },
         {
-          "name": "bumpWl",
-          "type": "u8"
-        },
-        {
-          "name": "whitelistType",
+          "name": "bumpFarmer",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "removeFromBankWhitelist",
+      "name": "unstake",
       "accounts": [
         {
           "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "farmManager",
+          "name": "farmTreasury",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "farmAuthority",
+          "name": "farmer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bank",
+          "name": "identity",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "addressToRemove",
+          "name": "bank",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "whitelistProof",
+          "name": "vault",
           "isMut": true,
           "isSigner": false
         },
@@ -1950,6 +2942,11 @@ export const IDL: GemFarm = {
           "name": "gemBank",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
@@ -1958,19 +2955,28 @@ export const IDL: GemFarm = {
           "type": "u8"
         },
         {
-          "name": "bumpWl",
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpFarmer",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "initFarmer",
+      "name": "claim",
       "accounts": [
         {
           "name": "farm",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "farmer",
           "isMut": true,
@@ -1978,53 +2984,81 @@ export const IDL: GemFarm = {
         },
         {
           "name": "identity",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "bank",
+          "name": "rewardAPot",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "vault",
+          "name": "rewardAMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardADestination",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "gemBank",
+          "name": "rewardBPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "rewardBDestination",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "feeAcc",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
         {
           "name": "bumpFarmer",
           "type": "u8"
         },
         {
-          "name": "bumpVault",
+          "name": "bumpPotA",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPotB",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "stake",
+      "name": "flashDeposit",
       "accounts": [
         {
           "name": "farm",
@@ -2057,88 +3091,85 @@ export const IDL: GemFarm = {
           "isSigner": false
         },
         {
-          "name": "gemBank",
+          "name": "vaultAuthority",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpAuth",
-          "type": "u8"
         },
         {
-          "name": "bumpFarmer",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "unstake",
-      "accounts": [
-        {
-          "name": "farm",
+          "name": "gemBox",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "farmAuthority",
-          "isMut": false,
+          "name": "gemDepositReceipt",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "farmTreasury",
+          "name": "gemSource",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "farmer",
-          "isMut": true,
+          "name": "gemMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "identity",
-          "isMut": true,
-          "isSigner": true
+          "name": "gemRarity",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "bank",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vault",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "gemBank",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "gemBank",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bumpAuth",
+          "name": "bumpFarmer",
           "type": "u8"
         },
         {
-          "name": "bumpTreasury",
+          "name": "bumpVaultAuth",
           "type": "u8"
         },
         {
-          "name": "bumpFarmer",
+          "name": "bumpGemBox",
           "type": "u8"
+        },
+        {
+          "name": "bumpGdr",
+          "type": "u8"
+        },
+        {
+          "name": "bumpRarity",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "claim",
+      "name": "scheduleUnstake",
       "accounts": [
         {
           "name": "farm",
@@ -2146,8 +3177,8 @@ export const IDL: GemFarm = {
           "isSigner": false
         },
         {
-          "name": "farmAuthority",
-          "isMut": false,
+          "name": "farmTreasury",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -2161,42 +3192,27 @@ export const IDL: GemFarm = {
           "isSigner": true
         },
         {
-          "name": "rewardAPot",
+          "name": "unstakeSchedule",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rewardAMint",
+          "name": "bank",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rewardADestination",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "rewardBPot",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "rewardBMint",
+          "name": "vault",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rewardBDestination",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
+          "name": "gemDepositReceipt",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "gemRarity",
           "isMut": false,
           "isSigner": false
         },
@@ -2204,16 +3220,11 @@ export const IDL: GemFarm = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bumpAuth",
+          "name": "bumpTreasury",
           "type": "u8"
         },
         {
@@ -2221,21 +3232,29 @@ export const IDL: GemFarm = {
           "type": "u8"
         },
         {
-          "name": "bumpPotA",
+          "name": "bumpSchedule",
           "type": "u8"
         },
         {
-          "name": "bumpPotB",
+          "name": "bumpRarity",
           "type": "u8"
+        },
+        {
+          "name": "gems",
+          "type": "u64"
+        },
+        {
+          "name": "unlockTs",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "flashDeposit",
+      "name": "withdrawUnlockedTranche",
       "accounts": [
         {
           "name": "farm",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -2253,6 +3272,11 @@ export const IDL: GemFarm = {
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "unstakeSchedule",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "bank",
           "isMut": false,
@@ -2279,7 +3303,7 @@ export const IDL: GemFarm = {
           "isSigner": false
         },
         {
-          "name": "gemSource",
+          "name": "gemDestination",
           "isMut": true,
           "isSigner": false
         },
@@ -2298,6 +3322,11 @@ export const IDL: GemFarm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -2315,10 +3344,18 @@ export const IDL: GemFarm = {
         }
       ],
       "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
         {
           "name": "bumpFarmer",
           "type": "u8"
         },
+        {
+          "name": "bumpSchedule",
+          "type": "u8"
+        },
         {
           "name": "bumpVaultAuth",
           "type": "u8"
@@ -2335,9 +3372,9 @@ export const IDL: GemFarm = {
           "name": "bumpRarity",
           "type": "u8"
         },
-        {
-          "name": "amount",
-          "type": "u64"
+        {
+          "name": "index",
+          "type": "u8"
         }
       ]
     },
@@ -2610,6 +3647,90 @@ export const IDL: GemFarm = {
         }
       ]
     },
+    {
+      "name": "sunsetReward",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        }
+      ]
+    },
     {
       "name": "lockReward",
       "accounts": [
@@ -2631,6 +3752,98 @@ export const IDL: GemFarm = {
       ],
       "args": []
     },
+    {
+      "name": "lockRewardUntil",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lockEndTs",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "calcSolvencyRatio",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "calcAprFunding",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "targetAprBps",
+          "type": "u64"
+        },
+        {
+          "name": "gemPrice",
+          "type": "u64"
+        },
+        {
+          "name": "rewardPrice",
+          "type": "u64"
+        },
+        {
+          "name": "gems",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "durationSec",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "reportFrozenEscrow",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardAPot",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBPot",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
     {
       "name": "addRaritiesToBank",
       "accounts": [
@@ -2783,12 +3996,24 @@ export const IDL: GemFarm = {
               "defined": "FarmReward"
             }
           },
+          {
+            "name": "maxCatchupPerCall",
+            "type": "u64"
+          },
+          {
+            "name": "flags",
+            "type": "u32"
+          },
+          {
+            "name": "creditTracking",
+            "type": "u8"
+          },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                64
+                51
               ]
             }
           }
@@ -2857,6 +4082,67 @@ export const IDL: GemFarm = {
           }
         ]
       }
+    },
+    {
+      "name": "farmerProfile",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "farmer",
+            "type": "publicKey"
+          },
+          {
+            "name": "delegate",
+            "type": "publicKey"
+          },
+          {
+            "name": "referrer",
+            "type": "publicKey"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "unstakeSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "farmer",
+            "type": "publicKey"
+          },
+          {
+            "name": "tranches",
+            "type": {
+              "array": [
+                {
+                  "defined": "UnstakeTranche"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
     }
   ],
   "types": [
@@ -2963,12 +4249,24 @@ export const IDL: GemFarm = {
               "defined": "TimeTracker"
             }
           },
+          {
+            "name": "totalClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "totalCreditedToFarmers",
+            "type": "u64"
+          },
+          {
+            "name": "totalDustSwept",
+            "type": "u64"
+          },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                32
+                8
               ]
             }
           }
@@ -3000,12 +4298,16 @@ export const IDL: GemFarm = {
               "defined": "FarmerFixedRateReward"
             }
           },
+          {
+            "name": "pendingCatchupReward",
+            "type": "u64"
+          },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                32
+                24
               ]
             }
           }
@@ -3074,6 +4376,30 @@ export const IDL: GemFarm = {
         ]
       }
     },
+    {
+      "name": "UnstakeTranche",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gemMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "gems",
+            "type": "u64"
+          },
+          {
+            "name": "rarityPoints",
+            "type": "u64"
+          },
+          {
+            "name": "unlockTs",
+            "type": "u64"
+          }
+        ]
+      }
+    },
     {
       "name": "TierConfig",
       "type": {
@@ -294,6 +294,47 @@ describe('gem bank', () => {
       ).to.be.rejectedWith('has_one');
     });
 
+    it('deposits a batch of gems in a single tx', async () => {
+      const oldVaultAcc = await gb.fetchVaultAcc(vault);
+
+      const gems = [];
+      for (let i = 0; i < 3; i++) {
+        const { gemAmount, gem } = await prepGem(vaultOwner);
+        gems.push({
+          gemAmount,
+          gemMint: gem.tokenMint,
+          gemSource: gem.tokenAcc,
+        });
+      }
+      const totalAmount = gems.reduce((a, g) => a.add(g.gemAmount), new BN(0));
+
+      const { vaultAuth, gemBoxes, GDRs } = await gb.depositGemsBatch(
+        bank.publicKey,
+        vault,
+        vaultOwner,
+        gems
+      );
+
+      const vaultAcc = await gb.fetchVaultAcc(vault);
+      assert(vaultAcc.gemBoxCount.eq(oldVaultAcc.gemBoxCount.add(new BN(3))));
+      assert(vaultAcc.gemCount.eq(oldVaultAcc.gemCount.add(totalAmount)));
+      assert(
+        vaultAcc.rarityPoints.eq(oldVaultAcc.rarityPoints.add(totalAmount))
+      );
+
+      for (let i = 0; i < 3; i++) {
+        const gemBoxAcc = await gb.fetchGemAcc(gems[i].gemMint, gemBoxes[i]);
+        assert(gemBoxAcc.amount.eq(gems[i].gemAmount));
+        assert.equal(gemBoxAcc.owner.toBase58(), vaultAuth.toBase58());
+
+        const GDRAcc = await gb.fetchGDRAcc(GDRs[i]);
+        assert.equal(GDRAcc.vault.toBase58(), vault.toBase58());
+        assert.equal(GDRAcc.gemBoxAddress.toBase58(), gemBoxes[i].toBase58());
+        assert.equal(GDRAcc.gemMint.toBase58(), gems[i].gemMint.toBase58());
+        assert(GDRAcc.gemCount.eq(gems[i].gemAmount));
+      }
+    });
+
     // --------------------------------------- vault lock
 
     async function prepLock(vaultLocked: boolean) {
@@ -69,6 +69,21 @@ describe('funding (fixed rate)', () => {
     await expect(gf.callCancelReward()).to.be.rejectedWith('0x155');
   });
 
+  it('FAILS to lock a fixed reward only partially', async () => {
+    await gf.callFundReward(undefined, defaultFixedConfig);
+
+    const times = await gf.verifyTimes(100);
+    const midpointTs = times.rewardEndTs.sub(new BN(50));
+
+    await expect(gf.callLockRewardUntil(midpointTs)).to.be.rejectedWith(
+      '0x12e'
+    );
+
+    //nothing got locked, so the reward can still be cancelled
+    await gf.callCancelReward();
+    await gf.verifyFunds(30000, 30000, 0);
+  });
+
   it('funds -> cancels (no stakers)', async () => {
     await gf.callFundReward(undefined, defaultFixedConfig);
     let oldEndTs = (await gf.verifyTimes()).rewardEndTs;
@@ -1,4 +1,9 @@
-import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  Transaction,
+} from '@solana/web3.js';
 import {
   FarmConfig,
   FixedRateConfig,
@@ -15,7 +20,11 @@ import {
 } from '../../sdk/src';
 import * as anchor from '@project-serum/anchor';
 import { BN } from '@project-serum/anchor';
-import { Token } from '@solana/spl-token';
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  Token,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
 import { assert } from 'chai';
 
 // --------------------------------------- configs
@@ -220,6 +229,47 @@ export class GemFarmTester extends GemFarmClient {
     );
   }
 
+  async callPayoutTokens(receiver: PublicKey, amount: Numerical) {
+    return this.payoutTokensFromTreasury(
+      this.farm.publicKey,
+      this.farmManager,
+      this.rewardMint.publicKey,
+      receiver,
+      toBN(amount)
+    );
+  }
+
+  // the treasury is a PDA, so its ATA can't be created through Token
+  async fundTreasuryTokens(amount: Numerical) {
+    const [treasury] = await this.findFarmTreasuryPDA(this.farm.publicKey);
+    const treasuryATA = await this.findATA(
+      this.rewardMint.publicKey,
+      treasury,
+      true
+    );
+
+    await this.provider.send(
+      new Transaction().add(
+        Token.createAssociatedTokenAccountInstruction(
+          ASSOCIATED_TOKEN_PROGRAM_ID,
+          TOKEN_PROGRAM_ID,
+          this.rewardMint.publicKey,
+          treasuryATA,
+          treasury,
+          this.funder.publicKey
+        )
+      )
+    );
+    await this.rewardMint.mintTo(
+      treasuryATA,
+      this.funder,
+      [],
+      toBN(amount).toNumber()
+    );
+
+    return treasuryATA;
+  }
+
   async callAddToBankWhitelist(
     addressToWhitelist: PublicKey,
     whitelistType: WhitelistType
@@ -242,8 +292,31 @@ export class GemFarmTester extends GemFarmClient {
 
   // ----------------- farmer
 
-  async callInitFarmer(identity: Keypair) {
-    return this.initFarmer(this.farm.publicKey, identity, identity);
+  async callInitFarmer(
+    identity: Keypair,
+    delegate?: PublicKey,
+    referrer?: PublicKey
+  ) {
+    return this.initFarmer(
+      this.farm.publicKey,
+      identity,
+      identity,
+      delegate,
+      referrer
+    );
+  }
+
+  async callUpdateFarmer(
+    identity: Keypair,
+    delegate?: PublicKey,
+    referrer?: PublicKey
+  ) {
+    return this.updateFarmer(
+      this.farm.publicKey,
+      identity,
+      delegate,
+      referrer
+    );
   }
 
   async callStake(identity: Keypair) {
@@ -318,6 +391,32 @@ export class GemFarmTester extends GemFarmClient {
     );
   }
 
+  async callScheduleUnstake(
+    gems: Numerical,
+    identity: Keypair,
+    unlockTs: Numerical
+  ) {
+    const isFarmer1 =
+      identity.publicKey.toBase58() ===
+      this.farmer1Identity.publicKey.toBase58();
+
+    return this.scheduleUnstake(
+      this.farm.publicKey,
+      identity,
+      isFarmer1 ? this.gem1.tokenMint : this.gem2.tokenMint,
+      toBN(gems),
+      toBN(unlockTs)
+    );
+  }
+
+  async callWithdrawUnlockedTranche(identity: Keypair, trancheIndex: number) {
+    return this.withdrawUnlockedTranche(
+      this.farm.publicKey,
+      identity,
+      trancheIndex
+    );
+  }
+
   async callRefreshFarmer(identity: Keypair | PublicKey, reenroll?: boolean) {
     return this.refreshFarmer(this.farm.publicKey, identity, reenroll);
   }
@@ -373,6 +472,33 @@ export class GemFarmTester extends GemFarmClient {
     );
   }
 
+  async callLockRewardUntil(lockEndTs: BN) {
+    return this.lockRewardUntil(
+      this.farm.publicKey,
+      this.farmManager,
+      this.rewardMint.publicKey,
+      lockEndTs
+    );
+  }
+
+  async callReportFrozenEscrow() {
+    return this.reportFrozenEscrow(this.farm.publicKey);
+  }
+
+  // the test wallet is the reward mint's freeze authority
+  async freezeRewardPot(freeze = true) {
+    const [pot] = await this.findRewardsPotPDA(
+      this.farm.publicKey,
+      this.rewardMint.publicKey
+    );
+
+    if (freeze) {
+      await this.rewardMint.freezeAccount(pot, this.funder, []);
+    } else {
+      await this.rewardMint.thawAccount(pot, this.funder, []);
+    }
+  }
+
   // --------------------------------------- rarities
 
   async callAddRaritiesToBank(rarityConfigs: RarityConfig[]) {
@@ -3,7 +3,7 @@ import chai, { assert, expect } from 'chai';
 import chaiAsPromised from 'chai-as-promised';
 import { GemFarmTester } from '../gem-farm.tester';
 import { FarmConfig, pause } from '../../../sdk/src';
-import { LAMPORTS_PER_SOL } from '@solana/web3.js';
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
 
 chai.use(chaiAsPromised);
 
@@ -67,4 +67,140 @@ describe('farmer lifecycle (unstaked -> staked -> cooldown)', () => {
     assert(farmerAcc.gemsStaked.eq(new BN(0)));
     assert(farmerAcc.rarityPointsStaked.eq(new BN(0)));
   });
+
+  it('inits farmer ahead of staking, then sets a referrer once', async () => {
+    //farmer 2 is initialized empty, before any gems are deposited
+    const { farmer, farmerProfile } = await gf.callInitFarmer(
+      gf.farmer2Identity
+    );
+
+    let farmerAcc = await gf.fetchFarmerAcc(farmer);
+    assert.equal(gf.parseFarmerState(farmerAcc), 'unstaked');
+    assert(farmerAcc.gemsStaked.eq(new BN(0)));
+    assert(farmerAcc.rewardA.accruedReward.eq(new BN(0)));
+    assert(farmerAcc.rewardB.accruedReward.eq(new BN(0)));
+
+    let profileAcc = await gf.fetchFarmerProfileAcc(farmerProfile);
+    assert(profileAcc.farmer.equals(farmer));
+    assert(profileAcc.delegate.equals(PublicKey.default));
+    assert(profileAcc.referrer.equals(PublicKey.default));
+
+    //farmers can't refer themselves
+    await expect(
+      gf.callUpdateFarmer(
+        gf.farmer2Identity,
+        undefined,
+        gf.farmer2Identity.publicKey
+      )
+    ).to.be.rejectedWith('0x12e');
+
+    //set farmer 1 as the referrer
+    await gf.callUpdateFarmer(
+      gf.farmer2Identity,
+      undefined,
+      gf.farmer1Identity.publicKey
+    );
+
+    //once set, the referrer can't be changed
+    await expect(
+      gf.callUpdateFarmer(
+        gf.farmer2Identity,
+        undefined,
+        Keypair.generate().publicKey
+      )
+    ).to.be.rejectedWith('0x165');
+
+    //staking reuses the existing farmer account
+    await gf.callDeposit(gf.gem2Amount, gf.farmer2Identity);
+    await gf.callStake(gf.farmer2Identity);
+
+    farmerAcc = await gf.fetchFarmerAcc(farmer);
+    assert.equal(gf.parseFarmerState(farmerAcc), 'staked');
+    assert(farmerAcc.gemsStaked.eq(gf.gem2Amount));
+
+    profileAcc = await gf.fetchFarmerProfileAcc(farmerProfile);
+    assert(profileAcc.referrer.equals(gf.farmer1Identity.publicKey));
+    assert(profileAcc.delegate.equals(PublicKey.default));
+
+    const farmAcc = await gf.fetchFarm();
+    assert(farmAcc.farmerCount.eq(new BN(2)));
+    assert(farmAcc.stakedFarmerCount.eq(new BN(1)));
+  });
+
+  it('unstakes gems in tranches over time', async () => {
+    await gf.callDeposit(gf.gem1Amount, gf.farmer1Identity);
+    const { farmer, vault } = await gf.callStake(gf.farmer1Identity);
+
+    //3 tranches of 10 gems, unlocking 3s apart (min staking / cooldown = 2s)
+    const treasuryBefore = await gf.fetchTreasuryBal();
+    const now = Math.floor(Date.now() / 1000);
+    const unlockTs = [now + 4, now + 7, now + 10];
+    let unstakeSchedule: PublicKey | undefined;
+    for (const ts of unlockTs) {
+      ({ unstakeSchedule } = await gf.callScheduleUnstake(
+        10,
+        gf.farmer1Identity,
+        ts
+      ));
+    }
+
+    //each tranche pays the unstaking fee
+    const treasuryAfter = await gf.fetchTreasuryBal();
+    assert.equal(treasuryAfter - treasuryBefore, 3 * LAMPORTS_PER_SOL);
+
+    //scheduled gems stop accruing right away
+    let farmerAcc = await gf.fetchFarmerAcc(farmer);
+    assert(farmerAcc.gemsStaked.eq(gf.gem1Amount.sub(new BN(30))));
+    let farmAcc = await gf.fetchFarm();
+    assert(farmAcc.gemsStaked.eq(gf.gem1Amount.sub(new BN(30))));
+
+    //the rest of the stake can't be scheduled - that's what unstaking is for
+    await expect(
+      gf.callScheduleUnstake(
+        gf.gem1Amount.sub(new BN(30)),
+        gf.farmer1Identity,
+        now + 10
+      )
+    ).to.be.rejectedWith('0x12e');
+
+    //nothing can be withdrawn before it unlocks
+    await expect(
+      gf.callWithdrawUnlockedTranche(gf.farmer1Identity, 0)
+    ).to.be.rejectedWith('0x162');
+
+    for (let i = 0; i < unlockTs.length; i++) {
+      await pause((unlockTs[i] + 1 - Math.floor(Date.now() / 1000)) * 1000);
+
+      //later tranches stay locked
+      if (i < unlockTs.length - 1) {
+        await expect(
+          gf.callWithdrawUnlockedTranche(gf.farmer1Identity, i + 1)
+        ).to.be.rejectedWith('0x162');
+      }
+
+      const { gemDestination } = await gf.callWithdrawUnlockedTranche(
+        gf.farmer1Identity,
+        i
+      );
+      const destAcc = await gf.fetchTokenAcc(
+        gf.gem1.tokenMint,
+        gemDestination
+      );
+      assert(destAcc.amount.eq(new BN(10 * (i + 1))));
+
+      //the rest of the stake stays locked in the vault
+      const vaultAcc = await gf.fetchVaultAcc(vault);
+      assert.isTrue(vaultAcc.locked);
+      assert(vaultAcc.gemCount.eq(gf.gem1Amount.sub(new BN(10 * (i + 1)))));
+    }
+
+    //tranches are freed up once withdrawn, and the rest is still staked
+    farmerAcc = await gf.fetchFarmerAcc(farmer);
+    assert.equal(gf.parseFarmerState(farmerAcc), 'staked');
+    assert(farmerAcc.gemsStaked.eq(gf.gem1Amount.sub(new BN(30))));
+    const scheduleAcc = await gf.fetchUnstakeScheduleAcc(unstakeSchedule!);
+    for (const tranche of scheduleAcc.tranches) {
+      assert(tranche.gems.eq(new BN(0)));
+    }
+  });
 });
@@ -9,7 +9,9 @@ import { BN } from '@project-serum/anchor';
 import { LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
 import {
   FarmConfig,
+  FarmFlags,
   feeAccount,
+  pause,
   RewardType,
   WhitelistType,
 } from '../../../sdk/src';
@@ -161,4 +163,63 @@ describe('misc', () => {
     const balance = await gf.getBalance(destination.publicKey);
     assert.equal(balance, LAMPORTS_PER_SOL / 2);
   });
+
+  it('pays out tokens from treasury', async () => {
+    // eg reward dust swept at sunset
+    const treasuryATA = await gf.fundTreasuryTokens(100);
+
+    const receiver = await gf.nw.createFundedWallet(0);
+
+    const { destination } = await gf.callPayoutTokens(receiver.publicKey, 60);
+
+    const destinationAcc = await gf.fetchTokenAcc(
+      gf.rewardMint.publicKey,
+      destination
+    );
+    assert(destinationAcc.amount.eq(new BN(60)));
+    const treasuryAcc = await gf.fetchTokenAcc(
+      gf.rewardMint.publicKey,
+      treasuryATA
+    );
+    assert(treasuryAcc.amount.eq(new BN(40)));
+  });
+
+  // --------------------------------------- frozen escrow
+
+  it('claims with a frozen escrow that owes nothing', async () => {
+    await gf.freezeRewardPot();
+
+    //nothing accrued from the frozen pot, so nothing is transferred out of it
+    await gf.callClaimRewards(gf.farmer1Identity);
+
+    await gf.freezeRewardPot(false);
+  });
+
+  it('FAILS to claim from a frozen escrow, flags the farm', async () => {
+    //get farmer 1 accruing from the reward
+    await gf.callAuthorize();
+    await gf.callFundReward(undefined, defaultFixedConfig);
+    await gf.callUnstake(gf.farmer1Identity); //ends cooldown
+    await gf.callStake(gf.farmer1Identity);
+    await pause(2000);
+
+    await gf.freezeRewardPot();
+
+    await expect(gf.callClaimRewards(gf.farmer1Identity)).to.be.rejectedWith(
+      '0x15f'
+    );
+
+    //the failed claim rolled back, so anyone can report it
+    await gf.callReportFrozenEscrow();
+    let farmAcc = await gf.fetchFarm();
+    assert.equal(farmAcc.flags, FarmFlags.EscrowFrozen);
+
+    //once thawed, claims go through again and the flag can be cleared
+    await gf.freezeRewardPot(false);
+    await gf.callClaimRewards(gf.farmer1Identity);
+
+    await gf.callReportFrozenEscrow();
+    farmAcc = await gf.fetchFarm();
+    assert.equal(farmAcc.flags, 0);
+  });
 });
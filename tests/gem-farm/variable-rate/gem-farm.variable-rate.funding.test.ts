@@ -72,6 +72,41 @@ describe('funding (variable rate)', () => {
     await expect(gf.callCancelReward()).to.be.rejectedWith('0x155');
   });
 
+  it('funds -> locks until midpoint -> cancels the rest', async () => {
+    await gf.callFundReward(defaultVariableConfig);
+    const oldEndTs = (await gf.verifyTimes(100)).rewardEndTs;
+    const midpointTs = oldEndTs.sub(new BN(50));
+
+    await gf.callLockRewardUntil(midpointTs);
+
+    //lock can't be shortened, and the locked part can't be topped up
+    await expect(
+      gf.callLockRewardUntil(midpointTs.sub(new BN(10)))
+    ).to.be.rejectedWith('0x15e');
+    await expect(gf.callFundReward(defaultVariableConfig)).to.be.rejectedWith(
+      '0x155'
+    );
+
+    const { pot } = await gf.callCancelReward();
+
+    // ----------------- tests
+    //funds - only what comes after the midpoint is refunded (50s at 100/s)
+    await gf.verifyFunds(10000, 5000, 0);
+
+    //times - the reward now ends where the lock does
+    await gf.verifyTimes(50, midpointTs, midpointTs);
+
+    //variable reward - rate is unchanged, so the first half accrues as promised
+    await gf.verifyVariableReward(100);
+
+    //token accounts
+    await gf.verifyFunderAccContains(5000);
+    await gf.verifyPotContains(pot, 5000);
+
+    //nothing unlocked is left to cancel
+    await expect(gf.callCancelReward()).to.be.rejectedWith('0x155');
+  });
+
   it('funds -> cancels (no stakers)', async () => {
     await gf.callFundReward(defaultVariableConfig);
     let oldEndTs = (await gf.verifyTimes()).rewardEndTs;